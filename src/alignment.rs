@@ -0,0 +1,199 @@
+use std::fmt;
+
+/// A single step of an edit script which transforms the source string into the target string.
+/// Positions are indices of characters (or elements for the generic functions), starting from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditOperation {
+    /// The source element at `src_pos` is equal to the target element at `tar_pos`.
+    Keep {
+        /// Position in the source
+        src_pos: usize,
+        /// Position in the target
+        tar_pos: usize,
+    },
+    /// The source element at `src_pos` is replaced with the target element at `tar_pos`.
+    Substitute {
+        /// Position in the source
+        src_pos: usize,
+        /// Position in the target
+        tar_pos: usize,
+    },
+    /// The source element at `src_pos` is removed.
+    Delete {
+        /// Position in the source
+        src_pos: usize,
+    },
+    /// The target element at `tar_pos` is added.
+    Insert {
+        /// Position in the target
+        tar_pos: usize,
+    },
+    /// The source element at `src_pos` is swapped with the next source element that isn't deleted.
+    /// They become the target element at `tar_pos` and the next target element that isn't inserted.
+    ///
+    /// In the restricted `Damerau-Levenshtein` distance the swapped elements are always adjacent.
+    /// The unrestricted distance may delete elements between them and insert elements between
+    /// the swapped ones, in which case the `Delete` and `Insert` operations follow the `Transpose`.
+    Transpose {
+        /// Position of the first swapped element in the source
+        src_pos: usize,
+        /// Position of the first swapped element in the target
+        tar_pos: usize,
+    },
+}
+
+impl EditOperation {
+    /// Returns `true` for every operation except [`EditOperation::Keep`].
+    pub fn is_edit(&self) -> bool {
+        !matches!(self, EditOperation::Keep { .. })
+    }
+
+    /// Move the operation by `src_offset` positions in the source and `tar_offset` positions in the target.
+    pub(crate) fn shifted(self, src_offset: usize, tar_offset: usize) -> Self {
+        match self {
+            EditOperation::Keep { src_pos, tar_pos } => EditOperation::Keep {
+                src_pos: src_pos + src_offset,
+                tar_pos: tar_pos + tar_offset,
+            },
+            EditOperation::Substitute { src_pos, tar_pos } => EditOperation::Substitute {
+                src_pos: src_pos + src_offset,
+                tar_pos: tar_pos + tar_offset,
+            },
+            EditOperation::Delete { src_pos } => EditOperation::Delete {
+                src_pos: src_pos + src_offset,
+            },
+            EditOperation::Insert { tar_pos } => EditOperation::Insert {
+                tar_pos: tar_pos + tar_offset,
+            },
+            EditOperation::Transpose { src_pos, tar_pos } => EditOperation::Transpose {
+                src_pos: src_pos + src_offset,
+                tar_pos: tar_pos + tar_offset,
+            },
+        }
+    }
+
+    /// The operation which transforms the target into the source.
+    pub(crate) fn inverted(self) -> Self {
+        match self {
+            EditOperation::Keep { src_pos, tar_pos } => EditOperation::Keep {
+                src_pos: tar_pos,
+                tar_pos: src_pos,
+            },
+            EditOperation::Substitute { src_pos, tar_pos } => EditOperation::Substitute {
+                src_pos: tar_pos,
+                tar_pos: src_pos,
+            },
+            EditOperation::Delete { src_pos } => EditOperation::Insert { tar_pos: src_pos },
+            EditOperation::Insert { tar_pos } => EditOperation::Delete { src_pos: tar_pos },
+            EditOperation::Transpose { src_pos, tar_pos } => EditOperation::Transpose {
+                src_pos: tar_pos,
+                tar_pos: src_pos,
+            },
+        }
+    }
+}
+
+/// An edit script together with the source and target strings aligned to each other.
+/// Characters which have no counterpart in the other string are aligned with [`Alignment::GAP`].
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{EditOperation, Levenshtein};
+///
+/// let alignment = Levenshtein {src: "kitten",  tar: "sitting"}.alignment();
+///
+/// assert_eq!("kitten-", alignment.src);
+/// assert_eq!("sitting", alignment.tar);
+/// assert_eq!(EditOperation::Substitute {src_pos: 0, tar_pos: 0}, alignment.operations[0]);
+/// assert_eq!(EditOperation::Insert {tar_pos: 6}, alignment.operations[6]);
+/// assert_eq!(3, alignment.distance());
+///
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    /// The edit operations in the order of their positions
+    pub operations: Vec<EditOperation>,
+    /// Aligned source string
+    pub src: String,
+    /// Aligned target string
+    pub tar: String,
+}
+
+impl Alignment {
+    /// The character aligned with inserted and deleted characters.
+    pub const GAP: char = '-';
+
+    pub(crate) fn new(src: &[char], tar: &[char], operations: Vec<EditOperation>) -> Self {
+        let mut aligned_src = String::new();
+        let mut aligned_tar = String::new();
+        let mut index = 0;
+
+        while index < operations.len() {
+            match operations[index] {
+                EditOperation::Keep { src_pos, tar_pos }
+                | EditOperation::Substitute { src_pos, tar_pos } => {
+                    aligned_src.push(src[src_pos]);
+                    aligned_tar.push(tar[tar_pos]);
+                }
+                EditOperation::Delete { src_pos } => {
+                    aligned_src.push(src[src_pos]);
+                    aligned_tar.push(Self::GAP);
+                }
+                EditOperation::Insert { tar_pos } => {
+                    aligned_src.push(Self::GAP);
+                    aligned_tar.push(tar[tar_pos]);
+                }
+                EditOperation::Transpose { src_pos, tar_pos } => {
+                    aligned_src.push(src[src_pos]);
+                    aligned_tar.push(tar[tar_pos]);
+
+                    // deletions and insertions between the swapped elements
+                    let mut next_src = src_pos + 1;
+                    let mut next_tar = tar_pos + 1;
+                    while let Some(&EditOperation::Delete { src_pos }) = operations.get(index + 1) {
+                        if src_pos != next_src {
+                            break;
+                        }
+                        aligned_src.push(src[src_pos]);
+                        aligned_tar.push(Self::GAP);
+                        next_src += 1;
+                        index += 1;
+                    }
+                    while let Some(&EditOperation::Insert { tar_pos }) = operations.get(index + 1) {
+                        if tar_pos != next_tar {
+                            break;
+                        }
+                        aligned_src.push(Self::GAP);
+                        aligned_tar.push(tar[tar_pos]);
+                        next_tar += 1;
+                        index += 1;
+                    }
+
+                    aligned_src.push(src[next_src]);
+                    aligned_tar.push(tar[next_tar]);
+                }
+            }
+            index += 1;
+        }
+
+        Alignment {
+            operations,
+            src: aligned_src,
+            tar: aligned_tar,
+        }
+    }
+
+    /// The number of operations which aren't [`EditOperation::Keep`].
+    /// It is equal to the distance the alignment was computed for.
+    pub fn distance(&self) -> usize {
+        self.operations.iter().filter(|op| op.is_edit()).count()
+    }
+}
+
+impl fmt::Display for Alignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.src)?;
+        write!(f, "{}", self.tar)
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Both sequences with every element replaced by its index in their common alphabet,
+/// so that per-element state can be kept in a `Vec` of `len` entries instead of a map.
+pub(crate) struct Alphabet {
+    pub(crate) src: Vec<usize>,
+    pub(crate) tar: Vec<usize>,
+    pub(crate) len: usize,
+}
+
+impl Alphabet {
+    /// Number the elements in the order they first appear.
+    pub(crate) fn new<T: Eq + Hash>(src: &[T], tar: &[T]) -> Self {
+        let mut indices: HashMap<&T, usize> = HashMap::new();
+        let mut index_of = |item| {
+            let next = indices.len();
+            *indices.entry(item).or_insert(next)
+        };
+
+        let src: Vec<usize> = src.iter().map(&mut index_of).collect();
+        let tar: Vec<usize> = tar.iter().map(&mut index_of).collect();
+
+        Alphabet {
+            src,
+            tar,
+            len: indices.len(),
+        }
+    }
+
+    /// Number the characters in sorted order, without hashing them.
+    pub(crate) fn of_chars(src: &[char], tar: &[char]) -> Self {
+        let mut chars: Vec<char> = src.iter().chain(tar).copied().collect();
+        chars.sort_unstable();
+        chars.dedup();
+
+        let index_of = |c: &char| chars.binary_search(c).unwrap();
+        Alphabet {
+            src: src.iter().map(index_of).collect(),
+            tar: tar.iter().map(index_of).collect(),
+            len: chars.len(),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::tokenizer::TOKENIZE_ERROR;
+use crate::weighted_jaccard::weighted_jaccard_of_counts;
+use crate::{Error, StringMetric, Tokenizer};
+use std::hash::Hash;
+
+/// Calculate the `Bag Jaccard` index between two multisets of tokens.
+/// Unlike [`Jaccard`](crate::Jaccard), a token which occurs several times is counted several times:
+/// the intersection takes the smaller count of each token and the union the larger one.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{BagJaccard, Jaccard};
+///
+/// let bag_jaccard = BagJaccard {src: "the cat sat on the mat".to_string(),  tar: "the cat on the mat".to_string(), qval: 0};
+///
+/// assert_eq!(0.16666666666666663, bag_jaccard.distance());
+/// assert_eq!(0.16666666666666663, bag_jaccard.normalized_distance());
+/// assert_eq!(0.8333333333333334, bag_jaccard.similarity());
+/// assert_eq!(0.8333333333333334, bag_jaccard.normalized_similarity());
+///
+/// assert_eq!(0.19999999999999996, Jaccard {src: "the cat sat on the mat",  tar: "the cat on the mat", qval: 0}.distance());
+///
+/// ```
+///
+pub struct BagJaccard<S = String, Q = usize> {
+    /// Source string
+    pub src: S,
+    /// Target string
+    pub tar: S,
+    /// q-gram value, or any other [`Tokenizer`].
+    /// * If `qval = 0` then q-grams are words
+    /// * If `qval = 1` then q-grams are chars
+    /// * If `qval > 1` then q-grams are ngrams of `qval` chars
+    pub qval: Q,
+}
+
+impl<S: AsRef<str>, Q: Tokenizer> BagJaccard<S, Q> {
+    /// Calculate the `Bag Jaccard` distance between two strings.
+    /// The distance is 1 minus the size of the intersection over the size of the union of the multisets.
+    /// The distance is always between 0.0 and 1.0.
+    /// When 0.0 then two strings have the same tokens, each as many times.
+    /// When 1.0 then two strings have no token in common.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::BagJaccard;
+    ///
+    /// let bag_jaccard = BagJaccard {src: "the the cat",  tar: "the cat", qval: 0};
+    ///
+    /// assert_eq!(0.33333333333333337, bag_jaccard.distance());
+    ///
+    /// ```
+    pub fn distance(&self) -> f64 {
+        self.try_distance().expect(TOKENIZE_ERROR)
+    }
+
+    /// Calculate the `Bag Jaccard` distance between two strings.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    pub fn try_distance(&self) -> Result<f64, Error> {
+        let src_tokens = self.qval.tokenize(self.src.as_ref())?;
+        let tar_tokens = self.qval.tokenize(self.tar.as_ref())?;
+
+        Ok(generic_bag_jaccard(&src_tokens, &tar_tokens))
+    }
+
+    /// Calculate the `normalized distance`.
+    /// The distance is already between 0.0 and 1.0, so it is equal to the distance.
+    pub fn normalized_distance(&self) -> f64 {
+        self.try_normalized_distance().expect(TOKENIZE_ERROR)
+    }
+
+    /// Calculate the `normalized distance`.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    pub fn try_normalized_distance(&self) -> Result<f64, Error> {
+        self.try_distance()
+    }
+
+    /// Calculate the `similarity`.
+    /// The similarity is the size of the intersection over the size of the union of the multisets.
+    pub fn similarity(&self) -> f64 {
+        self.try_similarity().expect(TOKENIZE_ERROR)
+    }
+
+    /// Calculate the `similarity`.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    pub fn try_similarity(&self) -> Result<f64, Error> {
+        let str_distance = self.try_distance()?;
+
+        Ok(1.0 - str_distance)
+    }
+
+    /// Calculate the `normalized similarity`.
+    /// The normalized similarity is 1 minus normalized distance.
+    pub fn normalized_similarity(&self) -> f64 {
+        self.try_normalized_similarity().expect(TOKENIZE_ERROR)
+    }
+
+    /// Calculate the `normalized similarity`.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    pub fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        let str_normalized_distance = self.try_normalized_distance()?;
+
+        Ok(1.0 - str_normalized_distance)
+    }
+}
+
+/// Calculate the `Bag Jaccard` distance between two collections of arbitrary tokens.
+/// Both collections are treated as multisets, so repeated tokens are counted as many times as they occur.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{generic_bag_jaccard, generic_jaccard};
+///
+/// assert_eq!(0.5, generic_bag_jaccard(&[1, 1, 2], &[1, 2, 2]));
+/// assert_eq!(0.0, generic_jaccard(&[1, 1, 2], &[1, 2, 2]));
+///
+/// ```
+pub fn generic_bag_jaccard<T: Eq + Hash>(src: &[T], tar: &[T]) -> f64 {
+    weighted_jaccard_of_counts(src, tar, |_| Ok(1.0)).unwrap()
+}
+
+impl<S: AsRef<str>, Q: Tokenizer> StringMetric for BagJaccard<S, Q> {
+    type Output = f64;
+
+    fn distance(&self) -> f64 {
+        BagJaccard::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        BagJaccard::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> f64 {
+        BagJaccard::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        BagJaccard::normalized_similarity(self)
+    }
+
+    fn try_distance(&self) -> Result<f64, Error> {
+        BagJaccard::try_distance(self)
+    }
+
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        BagJaccard::try_normalized_distance(self)
+    }
+
+    fn try_similarity(&self) -> Result<f64, Error> {
+        BagJaccard::try_similarity(self)
+    }
+
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        BagJaccard::try_normalized_similarity(self)
+    }
+}
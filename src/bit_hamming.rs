@@ -0,0 +1,206 @@
+use crate::Error;
+
+const LENGTH_MISMATCH: &str = "Hamming distance is only defined for bit strings of equal length";
+
+/// Unsigned integers used as words of bit strings, e.g. of perceptual hashes or `SimHash` fingerprints.
+/// The `Hamming` distance of two words is the number of set bits of their XOR,
+/// which compiles to a `popcount` instruction where the target supports it.
+pub trait BitWord: Copy {
+    /// Number of bits of the word
+    const BITS: usize;
+
+    /// Count the bits which differ in both words.
+    fn differing_bits(self, other: Self) -> usize;
+
+    /// Count the bits which differ in two slices of equal length.
+    fn differing_bits_of_slices(src: &[Self], tar: &[Self]) -> usize {
+        src.iter()
+            .zip(tar)
+            .map(|(s_word, t_word)| s_word.differing_bits(*t_word))
+            .sum()
+    }
+
+    /// Parse a word from digits in the given radix, or return `None` if they aren't valid or don't fit.
+    fn from_digits(digits: &str, radix: u32) -> Option<Self>;
+}
+
+macro_rules! impl_bit_word {
+    ($($word:ty),*) => {
+        $(
+            impl BitWord for $word {
+                const BITS: usize = <$word>::BITS as usize;
+
+                fn differing_bits(self, other: Self) -> usize {
+                    (self ^ other).count_ones() as usize
+                }
+
+                fn from_digits(digits: &str, radix: u32) -> Option<Self> {
+                    <$word>::from_str_radix(digits, radix).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_word!(u16, u32, u64, u128);
+
+impl BitWord for u8 {
+    const BITS: usize = u8::BITS as usize;
+
+    fn differing_bits(self, other: Self) -> usize {
+        (self ^ other).count_ones() as usize
+    }
+
+    // Compare 8 bytes at a time, as one `u64`.
+    fn differing_bits_of_slices(src: &[u8], tar: &[u8]) -> usize {
+        let src_chunks = src.chunks_exact(8);
+        let tar_chunks = tar.chunks_exact(8);
+        let remainder: usize = src_chunks
+            .remainder()
+            .iter()
+            .zip(tar_chunks.remainder())
+            .map(|(s_byte, t_byte)| s_byte.differing_bits(*t_byte))
+            .sum();
+
+        src_chunks
+            .zip(tar_chunks)
+            .map(|(s_chunk, t_chunk)| {
+                let s_word = u64::from_ne_bytes(s_chunk.try_into().unwrap());
+                let t_word = u64::from_ne_bytes(t_chunk.try_into().unwrap());
+                s_word.differing_bits(t_word)
+            })
+            .sum::<usize>()
+            + remainder
+    }
+
+    fn from_digits(digits: &str, radix: u32) -> Option<Self> {
+        u8::from_str_radix(digits, radix).ok()
+    }
+}
+
+/// Calculate the `Hamming` distance between two words, i.e. the number of differing bits.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::bit_hamming;
+///
+/// assert_eq!(2, bit_hamming(0b1011u8, 0b1110u8));
+/// assert_eq!(64, bit_hamming(0u64, u64::MAX));
+/// assert_eq!(1, bit_hamming(1u128 << 100, 0u128));
+///
+/// ```
+pub fn bit_hamming<W: BitWord>(src: W, tar: W) -> usize {
+    src.differing_bits(tar)
+}
+
+/// Calculate the `Hamming` distance between two bit strings stored in words of equal number,
+/// e.g. a 256-bit fingerprint in `[u64; 4]` or `[u8; 32]`.
+/// If not equal length, then panic.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_bit_hamming;
+///
+/// assert_eq!(3, generic_bit_hamming(&[0xffu8, 0x0f], &[0xfe, 0x0c]));
+/// assert_eq!(128, generic_bit_hamming(&[0u64, u64::MAX], &[u64::MAX, 0]));
+///
+/// ```
+pub fn generic_bit_hamming<W: BitWord>(src: &[W], tar: &[W]) -> usize {
+    try_generic_bit_hamming(src, tar).expect(LENGTH_MISMATCH)
+}
+
+/// Calculate the `Hamming` distance between two bit strings stored in words of equal number.
+/// If not equal length, then return [`Error::LengthMismatch`] with the numbers of words.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{try_generic_bit_hamming, Error};
+///
+/// assert_eq!(Ok(1), try_generic_bit_hamming(&[1u64, 2], &[1, 3]));
+/// assert_eq!(Err(Error::LengthMismatch {src_len: 2, tar_len: 1}), try_generic_bit_hamming(&[1u64, 2], &[1]));
+///
+/// ```
+pub fn try_generic_bit_hamming<W: BitWord>(src: &[W], tar: &[W]) -> Result<usize, Error> {
+    if src.len() != tar.len() {
+        return Err(Error::LengthMismatch {
+            src_len: src.len(),
+            tar_len: tar.len(),
+        });
+    }
+
+    Ok(W::differing_bits_of_slices(src, tar))
+}
+
+/// Parse a bit string written with hexadecimal digits into words, e.g. a fingerprint printed as hex.
+/// The digits are read from the most significant, so each word gets the next `W::BITS / 4` digits,
+/// e.g. two digits per `u8` as with the usual hex encoding of bytes.
+/// An optional `0x` or `0X` prefix and `_` between digits are ignored.
+/// If a char isn't a hexadecimal digit, or the number of digits isn't a multiple of the digits of a word,
+/// then return [`Error::Parse`] at line 1.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{bits_from_hex, generic_bit_hamming};
+///
+/// let bytes: Vec<u8> = bits_from_hex("0xdead_beef").unwrap();
+/// let words: Vec<u64> = bits_from_hex("00000000deadbeefffffffffffffffff").unwrap();
+///
+/// assert_eq!(vec![0xde, 0xad, 0xbe, 0xef], bytes);
+/// assert_eq!(vec![0xdeadbeef, u64::MAX], words);
+/// assert_eq!(2, generic_bit_hamming(&words, &bits_from_hex("00000000deadbeeffffffffffffffffc").unwrap()));
+///
+/// ```
+pub fn bits_from_hex<W: BitWord>(text: &str) -> Result<Vec<W>, Error> {
+    let digits = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")).unwrap_or(text);
+    parse_words(digits, 16, W::BITS / 4)
+}
+
+/// Parse a bit string written with binary digits into words.
+/// The digits are read from the most significant, so each word gets the next `W::BITS` digits.
+/// An optional `0b` or `0B` prefix and `_` between digits are ignored.
+/// If a char isn't a binary digit, or the number of digits isn't a multiple of the bits of a word,
+/// then return [`Error::Parse`] at line 1.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{bit_hamming, bits_from_binary, Error};
+///
+/// let words: Vec<u8> = bits_from_binary("0b1011_0000_0000_0001").unwrap();
+///
+/// assert_eq!(vec![0b1011_0000, 0b0000_0001], words);
+/// assert_eq!(4, bit_hamming(words[0], 0b0100_0000));
+/// assert!(matches!(bits_from_binary::<u8>("1011"), Err(Error::Parse {..})));
+///
+/// ```
+pub fn bits_from_binary<W: BitWord>(text: &str) -> Result<Vec<W>, Error> {
+    let digits = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")).unwrap_or(text);
+    parse_words(digits, 2, W::BITS)
+}
+
+fn parse_words<W: BitWord>(text: &str, radix: u32, digits_per_word: usize) -> Result<Vec<W>, Error> {
+    let parse_error = |reason: String| Error::Parse { line: 1, reason };
+
+    let digits: String = text.chars().filter(|&c| c != '_').collect();
+    if let Some(c) = digits.chars().find(|c| !c.is_digit(radix)) {
+        return Err(parse_error(format!("expected a digit in base {}, got {:?}", radix, c)));
+    }
+    if !digits.len().is_multiple_of(digits_per_word) {
+        return Err(parse_error(format!(
+            "expected a multiple of {} digits, got {}",
+            digits_per_word,
+            digits.len()
+        )));
+    }
+
+    // The digits are ASCII, so chunks of bytes are chunks of digits.
+    Ok(digits
+        .as_bytes()
+        .chunks(digits_per_word)
+        .map(|chunk| W::from_digits(std::str::from_utf8(chunk).unwrap(), radix).unwrap())
+        .collect())
+}
@@ -0,0 +1,202 @@
+// Bit-parallel edit distances.
+//
+// Each column of the dynamic programming matrix is encoded as bit vectors of vertical deltas
+// (`+1` or `-1` between neighbouring cells), so a whole column is computed with a handful of
+// word operations. Patterns up to 64 elements fit in a single `u64`, longer ones are split into
+// blocks of 64 rows which pass the horizontal delta of their last row to the next block.
+//
+// * Myers, G. (1999). A fast bit-vector algorithm for approximate string matching based on dynamic programming.
+// * Hyyrö, H. (2003). A bit-vector algorithm for computing Levenshtein and Damerau edit distances.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+const WORD_SIZE: usize = 64;
+const HIGH_BIT: u64 = 1 << (WORD_SIZE - 1);
+
+/// Masks of the positions where each element occurs in the pattern, one `u64` per block of 64 elements.
+#[derive(Debug, Clone)]
+pub(crate) struct PatternMasks<K> {
+    masks: HashMap<K, Vec<u64>>,
+    empty: Vec<u64>,
+    len: usize,
+}
+
+impl<K: Eq + Hash> PatternMasks<K> {
+    pub(crate) fn new<I: ExactSizeIterator<Item = K>>(pattern: I) -> Self {
+        let len = pattern.len();
+        let blocks = len.div_ceil(WORD_SIZE);
+        let mut masks: HashMap<K, Vec<u64>> = HashMap::new();
+        for (i, item) in pattern.enumerate() {
+            masks.entry(item).or_insert_with(|| vec![0; blocks])[i / WORD_SIZE] |= 1 << (i % WORD_SIZE);
+        }
+
+        PatternMasks {
+            masks,
+            empty: vec![0; blocks],
+            len,
+        }
+    }
+
+    fn get(&self, item: &K) -> &[u64] {
+        self.masks.get(item).unwrap_or(&self.empty)
+    }
+
+    /// Calculate the Levenshtein distance between the pattern and `text` with Myers' algorithm,
+    /// or the restricted Damerau-Levenshtein (optimal string alignment) distance
+    /// with Hyyrö's extension when `transpositions` is `true`.
+    pub(crate) fn distance<I: Iterator<Item = K>>(&self, text: I, transpositions: bool) -> usize {
+        if self.len == 0 {
+            text.count()
+        } else if self.len <= WORD_SIZE {
+            single_word_distance(self, text, transpositions)
+        } else {
+            multi_word_distance(self, text, transpositions)
+        }
+    }
+}
+
+/// The vertical deltas of one block of a column.
+#[derive(Clone, Copy)]
+struct Block {
+    /// Rows where the value increases by one
+    positive: u64,
+    /// Rows where the value decreases by one
+    negative: u64,
+    /// Rows where the value is the same as in the previous row and column
+    diagonal_zero: u64,
+}
+
+impl Block {
+    fn new() -> Self {
+        Block {
+            positive: !0,
+            negative: 0,
+            diagonal_zero: 0,
+        }
+    }
+
+    /// Compute the next column of the block, given the mask of matching rows, the transpositions
+    /// and the horizontal delta entering the first row. Return the horizontal delta leaving `last_bit`.
+    fn advance(&mut self, matches: u64, transpositions: u64, delta_in: i8, last_bit: u64) -> i8 {
+        let Block {
+            positive: vp,
+            negative: vn,
+            ..
+        } = *self;
+
+        // a negative delta entering the block behaves like a match in its first row
+        let eq = if delta_in < 0 { matches | 1 } else { matches };
+        let d0 = (((eq & vp).wrapping_add(vp)) ^ vp) | eq | vn | transpositions;
+        let mut hp = vn | !(d0 | vp);
+        let mut hn = vp & d0;
+
+        let delta_out = if hp & last_bit != 0 {
+            1
+        } else if hn & last_bit != 0 {
+            -1
+        } else {
+            0
+        };
+
+        hp <<= 1;
+        hn <<= 1;
+        if delta_in < 0 {
+            hn |= 1;
+        } else if delta_in > 0 {
+            hp |= 1;
+        }
+
+        self.positive = hn | !(d0 | hp);
+        self.negative = hp & d0;
+        self.diagonal_zero = d0;
+
+        delta_out
+    }
+}
+
+/// Calculate the Levenshtein distance with Myers' algorithm,
+/// or the restricted Damerau-Levenshtein (optimal string alignment) distance
+/// with Hyyrö's extension when `transpositions` is `true`.
+pub(crate) fn bit_parallel_distance<T: Eq + Hash>(src: &[T], tar: &[T], transpositions: bool) -> usize {
+    // both distances are symmetric, so the shorter sequence is the pattern
+    let (pattern, text) = if src.len() <= tar.len() {
+        (src, tar)
+    } else {
+        (tar, src)
+    };
+
+    PatternMasks::new(pattern.iter()).distance(text.iter(), transpositions)
+}
+
+fn single_word_distance<K: Eq + Hash, I: Iterator<Item = K>>(
+    masks: &PatternMasks<K>,
+    text: I,
+    transpositions: bool,
+) -> usize {
+    let last_bit = 1 << (masks.len - 1);
+    let mut block = Block::new();
+    let mut previous_matches = 0;
+    let mut distance = masks.len;
+
+    for item in text {
+        let matches = masks.get(&item)[0];
+        let swapped = if transpositions {
+            ((!block.diagonal_zero & matches) << 1) & previous_matches
+        } else {
+            0
+        };
+
+        // the first row of the matrix always increases by one
+        match block.advance(matches, swapped, 1, last_bit) {
+            1 => distance += 1,
+            -1 => distance -= 1,
+            _ => {}
+        }
+        previous_matches = matches;
+    }
+
+    distance
+}
+
+fn multi_word_distance<K: Eq + Hash, I: Iterator<Item = K>>(
+    masks: &PatternMasks<K>,
+    text: I,
+    transpositions: bool,
+) -> usize {
+    let blocks_len = masks.len.div_ceil(WORD_SIZE);
+    let last_bit = 1 << ((masks.len - 1) % WORD_SIZE);
+    let mut blocks = vec![Block::new(); blocks_len];
+    let mut previous_matches: &[u64] = &masks.empty;
+    let mut distance = masks.len;
+
+    for item in text {
+        let matches = masks.get(&item);
+        let mut delta = 1;
+        // the bit shifted out of the previous block's transposition candidates
+        let mut carry = 0;
+
+        for (index, block) in blocks.iter_mut().enumerate() {
+            let swapped = if transpositions {
+                let candidates = !block.diagonal_zero & matches[index];
+                let swapped = ((candidates << 1) | carry) & previous_matches[index];
+                carry = candidates >> (WORD_SIZE - 1);
+                swapped
+            } else {
+                0
+            };
+
+            let high_bit = if index + 1 == blocks_len { last_bit } else { HIGH_BIT };
+            delta = block.advance(matches[index], swapped, delta, high_bit);
+        }
+
+        match delta {
+            1 => distance += 1,
+            -1 => distance -= 1,
+            _ => {}
+        }
+        previous_matches = matches;
+    }
+
+    distance
+}
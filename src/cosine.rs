@@ -0,0 +1,63 @@
+use crate::set_metric::{empty_set_distance, set_metric, set_sizes};
+use crate::tokenizer::TOKENIZE_ERROR;
+use std::hash::Hash;
+
+set_metric! {
+    /// Calculate the `Cosine` similarity (`Ochiai` coefficient) between two sets of tokens.
+    /// It is the size of the intersection divided by the geometric mean of the sizes of the sets,
+    /// i.e. the cosine of the angle between the binary vectors of the sets.
+    /// An empty set has similarity 1.0 with another empty set and 0.0 with a non-empty one.
+    /// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Cosine_similarity#Otsuka%E2%80%93Ochiai_coefficient)
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Cosine;
+    ///
+    /// let cosine = Cosine {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0};
+    ///
+    /// assert_eq!(0.3675444679663241, cosine.distance());
+    /// assert_eq!(0.3675444679663241, cosine.normalized_distance());
+    /// assert_eq!(0.6324555320336759, cosine.similarity());
+    /// assert_eq!(0.6324555320336759, cosine.normalized_similarity());
+    ///
+    /// ```
+    ///
+    /// Bigrams of chars:
+    ///
+    /// ```
+    /// use text_distance::Cosine;
+    ///
+    /// let cosine = Cosine {src: "night",  tar: "nacht", qval: 2};
+    ///
+    /// assert_eq!(0.75, cosine.distance());
+    ///
+    /// ```
+    ///
+    pub struct Cosine {}
+    metric: "Cosine",
+    coefficient: "cosine similarity",
+    expect: TOKENIZE_ERROR,
+    errors: [],
+    distance: |_, src_tokens, tar_tokens| Ok(generic_cosine(&src_tokens, &tar_tokens)),
+}
+
+/// Calculate the `Cosine` distance between two collections of arbitrary tokens.
+/// Both collections are treated as sets, so repeated tokens are counted once.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_cosine;
+///
+/// assert_eq!(0.5917517095361369, generic_cosine(&[1, 2, 3], &[3, 4]));
+///
+/// ```
+pub fn generic_cosine<T: Eq + Hash>(src: &[T], tar: &[T]) -> f64 {
+    let (src_len, tar_len, intersection_len) = set_sizes(src, tar);
+    if let Some(distance) = empty_set_distance(src_len, tar_len) {
+        return distance;
+    }
+
+    1.0 - intersection_len as f64 / ((src_len * tar_len) as f64).sqrt()
+}
@@ -0,0 +1,289 @@
+use crate::{Error, Score};
+use std::collections::HashMap;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+/// A numeric type that can be used as the cost of an edit operation.
+/// It is implemented for `usize` (integer costs) and `f64` (floating-point costs).
+pub trait Cost: Score + Add<Output = Self> + Sub<Output = Self> {
+    /// The cost of doing nothing.
+    fn zero() -> Self;
+
+    /// The cost of repeating an operation `count` times.
+    fn times(self, count: usize) -> Self;
+}
+
+impl Cost for usize {
+    fn zero() -> Self {
+        0
+    }
+
+    fn times(self, count: usize) -> Self {
+        self * count
+    }
+}
+
+impl Cost for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn times(self, count: usize) -> Self {
+        self * count as f64
+    }
+}
+
+/// NaN is not comparable to zero, so it is rejected as well.
+pub(crate) fn is_valid_cost<C: Cost>(cost: C) -> bool {
+    cost.partial_cmp(&C::zero()).is_some() && cost >= C::zero()
+}
+
+/// Check that none of the named costs is negative.
+pub(crate) fn validate_costs<C: Cost, const N: usize>(costs: [(&'static str, C); N]) -> Result<(), Error> {
+    for (name, cost) in costs {
+        if !is_valid_cost(cost) {
+            return Err(Error::InvalidParameter {
+                name,
+                reason: format!("cost must not be negative, got {:?}", cost),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn min_cost<C: Cost>(a: C, b: C) -> C {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+pub(crate) fn max_cost<C: Cost>(a: C, b: C) -> C {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// `a - b`, or zero if `b` is larger, which `usize` costs can't subtract.
+pub(crate) fn saturating_sub<C: Cost>(a: C, b: C) -> C {
+    if b > a {
+        C::zero()
+    } else {
+        a - b
+    }
+}
+
+/// The cost of replacing one element with another.
+/// It is implemented for plain costs (`usize`, `f64`), which make every substitution cost the same,
+/// for [`CostMatrix`], which assigns a cost to each pair of characters,
+/// and for [`SubstitutionFn`], which computes the cost with a function.
+pub trait SubstitutionCost<T = char> {
+    /// The type of the cost.
+    type Cost: Cost;
+
+    /// The cost of replacing `a` with `b`. It is only called for different elements.
+    fn substitution(&self, a: &T, b: &T) -> Self::Cost;
+
+    /// The largest cost [`substitution`](SubstitutionCost::substitution) can return.
+    /// It is used to normalize distances.
+    fn max_substitution(&self) -> Self::Cost;
+}
+
+impl<T> SubstitutionCost<T> for usize {
+    type Cost = usize;
+
+    fn substitution(&self, _a: &T, _b: &T) -> usize {
+        *self
+    }
+
+    fn max_substitution(&self) -> usize {
+        *self
+    }
+}
+
+impl<T> SubstitutionCost<T> for f64 {
+    type Cost = f64;
+
+    fn substitution(&self, _a: &T, _b: &T) -> f64 {
+        *self
+    }
+
+    fn max_substitution(&self) -> f64 {
+        *self
+    }
+}
+
+/// Substitution costs computed by a function.
+/// Only `max` is validated, so the function itself must return costs between zero and `max`,
+/// otherwise the distances are meaningless.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{SubstitutionCost, SubstitutionFn};
+///
+/// let case_insensitive = SubstitutionFn {
+///     function: |a: &char, b: &char| if a.to_lowercase().eq(b.to_lowercase()) { 0.1 } else { 1.0 },
+///     max: 1.0,
+/// };
+///
+/// assert_eq!(0.1, case_insensitive.substitution(&'a', &'A'));
+/// assert_eq!(1.0, case_insensitive.substitution(&'a', &'b'));
+///
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SubstitutionFn<F, C> {
+    /// Function returning the cost of replacing the first element with the second
+    pub function: F,
+    /// The largest cost the function returns
+    pub max: C,
+}
+
+impl<T, C: Cost, F: Fn(&T, &T) -> C> SubstitutionCost<T> for SubstitutionFn<F, C> {
+    type Cost = C;
+
+    fn substitution(&self, a: &T, b: &T) -> C {
+        (self.function)(a, b)
+    }
+
+    fn max_substitution(&self) -> C {
+        self.max
+    }
+}
+
+/// Substitution costs for pairs of characters.
+/// Pairs which aren't in the matrix cost `default`.
+/// Negative (or `NaN`) costs are rejected when they are added, so a matrix is always valid.
+///
+/// The matrix can be loaded from text where each line is a pair of characters
+/// followed by the cost of substituting one with the other, e.g. `O 0 0.2`.
+/// A line applies to both directions, so `O 0 0.2` also sets the cost of replacing `0` with `O`.
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{CostMatrix, SubstitutionCost};
+///
+/// let ocr = CostMatrix::parse("
+///     ## OCR confusions
+///     O 0 0.2
+///     l 1 0.3
+/// ", 1.0).unwrap();
+///
+/// assert_eq!(0.2, ocr.substitution(&'0', &'O'));
+/// assert_eq!(0.3, ocr.substitution(&'l', &'1'));
+/// assert_eq!(1.0, ocr.substitution(&'l', &'7'));
+///
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostMatrix<C = usize> {
+    default: C,
+    // the largest cost, kept up to date by `insert`
+    max: C,
+    costs: HashMap<(char, char), C>,
+}
+
+impl<C: Cost> CostMatrix<C> {
+    /// Create an empty matrix where every substitution costs `default`.
+    /// If `default` is negative (or `NaN`), then return [`Error::InvalidParameter`].
+    pub fn new(default: C) -> Result<Self, Error> {
+        validate_costs([("default", default)])?;
+
+        Ok(CostMatrix {
+            default,
+            max: default,
+            costs: HashMap::new(),
+        })
+    }
+
+    /// Set the cost of replacing `a` with `b`.
+    /// It doesn't change the cost of replacing `b` with `a`.
+    /// If `cost` is negative (or `NaN`), then return [`Error::InvalidParameter`] and don't change the matrix.
+    pub fn insert(&mut self, a: char, b: char, cost: C) -> Result<(), Error> {
+        validate_costs([("cost", cost)])?;
+
+        let replaced = self.costs.insert((a, b), cost);
+        if cost > self.max {
+            self.max = cost;
+        } else if replaced.is_some_and(|replaced| replaced == self.max && cost < replaced) {
+            // the largest cost was lowered, so another one may be the largest now
+            self.max = self.costs.values().fold(self.default, |max, &cost| max_cost(max, cost));
+        }
+
+        Ok(())
+    }
+
+    /// Set the cost of replacing `a` with `b` and `b` with `a`.
+    /// If `cost` is negative (or `NaN`), then return [`Error::InvalidParameter`] and don't change the matrix.
+    pub fn insert_symmetric(&mut self, a: char, b: char, cost: C) -> Result<(), Error> {
+        self.insert(a, b, cost)?;
+        self.insert(b, a, cost)
+    }
+
+    /// Get the cost of replacing `a` with `b`.
+    pub fn get(&self, a: char, b: char) -> C {
+        *self.costs.get(&(a, b)).unwrap_or(&self.default)
+    }
+
+    /// Load a matrix from lines of `a b cost`.
+    /// If a line is malformed or a cost is negative, then return [`Error::Parse`].
+    /// If `default` is negative, then return [`Error::InvalidParameter`].
+    pub fn parse(text: &str, default: C) -> Result<Self, Error>
+    where
+        C: FromStr,
+    {
+        let mut matrix = CostMatrix::new(default)?;
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let parse_error = |reason: &str| Error::Parse {
+                line: index + 1,
+                reason: reason.to_string(),
+            };
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 3 {
+                return Err(parse_error("expected `a b cost`"));
+            }
+            let a = single_char(fields[0]).ok_or_else(|| parse_error("expected a single character"))?;
+            let b = single_char(fields[1]).ok_or_else(|| parse_error("expected a single character"))?;
+            let cost: C = fields[2]
+                .parse()
+                .map_err(|_| parse_error("expected a number"))?;
+            matrix
+                .insert_symmetric(a, b, cost)
+                .map_err(|_| parse_error("cost must not be negative"))?;
+        }
+
+        Ok(matrix)
+    }
+}
+
+pub(crate) fn single_char(field: &str) -> Option<char> {
+    let mut chars = field.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+impl<C: Cost> SubstitutionCost<char> for CostMatrix<C> {
+    type Cost = C;
+
+    fn substitution(&self, a: &char, b: &char) -> C {
+        self.get(*a, *b)
+    }
+
+    fn max_substitution(&self) -> C {
+        self.max
+    }
+}
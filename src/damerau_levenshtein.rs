@@ -1,5 +1,8 @@
+use crate::alphabet::Alphabet;
+use crate::bit_parallel::bit_parallel_distance;
+use crate::{Alignment, EditOperation, StringMetric};
 use std::cmp::{max, min};
-use std::collections::HashMap;
+use std::hash::Hash;
 
 /// Calculate the `Damerau-Levenshtein` distance between two strings.
 /// The Damerau-Levenshtein distance is a string metric for measuring edit distance between two sequences.
@@ -20,108 +23,34 @@ use std::collections::HashMap;
 ///
 /// ```
 ///
-pub struct DamerauLevenshtein {
+/// Borrowed `&str` inputs work too, which avoids allocating a `String` per comparison:
+///
+/// ```
+/// use text_distance::DamerauLevenshtein;
+///
+/// let damerau_levenshtein = DamerauLevenshtein {src: "karolin",  tar: "kathrin", restricted: true};
+///
+/// assert_eq!(3, damerau_levenshtein.distance());
+///
+/// ```
+///
+pub struct DamerauLevenshtein<S = String> {
     /// Source string
-    pub src: String,
+    pub src: S,
     /// Target string
-    pub tar: String,
+    pub tar: S,
     /// * If `restricted = true` it calculates `Optimal String Alignment Distance`.
     /// * If `restricted = false` it calculates distance with `Adjacent Transpositions`.
     pub restricted: bool,
 }
 
-impl DamerauLevenshtein {
-    fn restricted_distance(&self) -> usize {
-        let src_len = self.src.chars().count();
-        let tar_len = self.tar.chars().count();
-
-        let mut matrix: Vec<Vec<usize>> = vec![vec![0; tar_len + 1]; src_len + 1];
-
-        for i in 0..(src_len + 1) {
-            matrix[i][0] = i;
-        }
-
-        for j in 0..(tar_len + 1) {
-            matrix[0][j] = j;
-        }
-
-        for (i, s_char) in self.src.chars().enumerate() {
-            for (j, t_char) in self.tar.chars().enumerate() {
-                let substitution_cost = if s_char == t_char { 0 } else { 1 };
-                let operations = [
-                    matrix[i][j + 1] + 1,             // deletion
-                    matrix[i + 1][j] + 1,             // insertion
-                    matrix[i][j] + substitution_cost, // substitution
-                ];
-
-                matrix[i + 1][j + 1] = *operations.iter().min().unwrap();
-
-                // transposition
-                if i > 0
-                    && j > 0
-                    && s_char == self.tar.chars().nth(j - 1).unwrap()
-                    && t_char == self.src.chars().nth(i - 1).unwrap()
-                {
-                    matrix[i + 1][j + 1] = min(
-                        matrix[i + 1][j + 1],                     // cost without swappping
-                        matrix[i - 1][j - 1] + substitution_cost, // cost with swapping
-                    );
-                }
-            }
-        }
-
-        matrix[src_len][tar_len]
-    }
-
-    fn unrestricted_distance(&self) -> usize {
-        let src_len = self.src.chars().count();
-        let tar_len = self.tar.chars().count();
-
-        let max_dist = src_len + tar_len;
-
-        let mut da: HashMap<char, usize> = HashMap::new();
-        let mut matrix: Vec<Vec<usize>> = vec![vec![0; tar_len + 2]; src_len + 2];
-        matrix[0][0] = max_dist;
-
-        for i in 0..(src_len + 1) {
-            matrix[i + 1][0] = max_dist;
-            matrix[i + 1][1] = i;
-        }
-
-        for j in 0..(tar_len + 1) {
-            matrix[0][j + 1] = max_dist;
-            matrix[1][j + 1] = j;
-        }
-
-        for i in 1..(src_len + 1) {
-            let mut db = 0;
-            for j in 1..(tar_len + 1) {
-                let k = *da.get(&self.tar.chars().nth(j - 1).unwrap()).unwrap_or(&0);
-                let l = db;
-
-                let mut substitution_cost = 1;
-                if self.src.chars().nth(i - 1).unwrap() == self.tar.chars().nth(j - 1).unwrap() {
-                    substitution_cost = 0;
-                    db = j;
-                }
-                let operations = [
-                    matrix[i][j] + substitution_cost,             // substitution
-                    matrix[i + 1][j] + 1,                         // insertion
-                    matrix[i][j + 1] + 1,                         // deletion
-                    matrix[k][l] + (i - k - 1) + 1 + (j - l - 1), // transposition
-                ];
-                matrix[i + 1][j + 1] = *operations.iter().min().unwrap();
-            }
-            da.insert(self.src.chars().nth(i - 1).unwrap(), i);
-        }
-
-        matrix[src_len + 1][tar_len + 1]
-    }
-
+impl<S: AsRef<str>> DamerauLevenshtein<S> {
     /// Calculate the `Damerau-Levenshtein` distance between two strings.
     /// The parameter `restricted` is used to determine the algorithm.
     /// If restricted is `true`, it calculates `Optimal String Alignment Distance`.
     /// If restricted is `false`, it calculates distance with `Adjacent Transpositions`.
+    /// The restricted distance is computed with Hyyrö's bit-parallel algorithm, 64 characters of the shorter string at a time.
+    /// The unrestricted distance takes O(n·m) time and memory, where n and m are the lengths of the strings.
     ///
     /// ### Examples
     ///
@@ -136,10 +65,13 @@ impl DamerauLevenshtein {
     ///
     /// ```
     pub fn distance(&self) -> usize {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
         if self.restricted {
-            self.restricted_distance()
+            bit_parallel_distance(&src, &tar, true)
         } else {
-            self.unrestricted_distance()
+            unrestricted_distance(&Alphabet::of_chars(&src, &tar))
         }
     }
 
@@ -160,8 +92,8 @@ impl DamerauLevenshtein {
     /// ```
     pub fn normalized_distance(&self) -> f64 {
         let maximum = max(
-            self.src.clone().chars().count(),
-            self.tar.clone().chars().count(),
+            self.src.as_ref().chars().count(),
+            self.tar.as_ref().chars().count(),
         );
         let str_distance = self.distance();
         if maximum != 0 {
@@ -188,8 +120,8 @@ impl DamerauLevenshtein {
     /// ```
     pub fn similarity(&self) -> usize {
         let maximum = max(
-            self.src.clone().chars().count(),
-            self.tar.clone().chars().count(),
+            self.src.as_ref().chars().count(),
+            self.tar.as_ref().chars().count(),
         );
         let str_distance = self.distance();
         maximum - str_distance
@@ -216,4 +148,418 @@ impl DamerauLevenshtein {
         let str_normalized_distance = self.normalized_distance();
         1.0 - str_normalized_distance
     }
+
+    /// Find the edit operations which transform the source string into the target string.
+    /// The number of operations other than [`EditOperation::Keep`] is equal to the distance.
+    /// Unlike [`Levenshtein::edit_script`](crate::Levenshtein::edit_script) it backtracks through the full matrix,
+    /// so it needs memory proportional to the product of the lengths.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{DamerauLevenshtein, EditOperation};
+    ///
+    /// let damerau_levenshtein = DamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false};
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         EditOperation::Transpose {src_pos: 0, tar_pos: 0},
+    ///         EditOperation::Insert {tar_pos: 1},
+    ///     ],
+    ///     damerau_levenshtein.edit_script()
+    /// );
+    ///
+    /// ```
+    pub fn edit_script(&self) -> Vec<EditOperation> {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        generic_damerau_levenshtein_edit_script(&src, &tar, self.restricted)
+    }
+
+    /// Find the edit operations and align the source and target strings to each other.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::DamerauLevenshtein;
+    ///
+    /// let restricted = DamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: true}.alignment();
+    /// let unrestricted = DamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false}.alignment();
+    ///
+    /// assert_eq!(("-ca", "abc"), (restricted.src.as_str(), restricted.tar.as_str()));
+    /// assert_eq!(("c-a", "abc"), (unrestricted.src.as_str(), unrestricted.tar.as_str()));
+    ///
+    /// ```
+    pub fn alignment(&self) -> Alignment {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+        let operations = generic_damerau_levenshtein_edit_script(&src, &tar, self.restricted);
+
+        Alignment::new(&src, &tar, operations)
+    }
+
+    /// Calculate the `Damerau-Levenshtein` distance if it is at most `max`.
+    /// If the distance is greater than `max`, then return `None`.
+    /// The restricted distance only fills a band of `2 * max + 1` diagonals of the matrix
+    /// and stops as soon as every cell of a row exceeds `max`.
+    /// The unrestricted distance is calculated in full and compared with `max`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::DamerauLevenshtein;
+    ///
+    /// let damerau_levenshtein = DamerauLevenshtein {src: "abcdef".to_string(),  tar: "badcfe".to_string(), restricted: true};
+    ///
+    /// assert_eq!(Some(3), damerau_levenshtein.distance_within(3));
+    /// assert_eq!(None, damerau_levenshtein.distance_within(2));
+    ///
+    /// ```
+    pub fn distance_within(&self, max: usize) -> Option<usize> {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        generic_damerau_levenshtein_within(&src, &tar, self.restricted, max)
+    }
+}
+
+fn restricted_matrix<T: Eq>(src: &[T], tar: &[T]) -> Vec<Vec<usize>> {
+    let mut matrix: Vec<Vec<usize>> = vec![vec![0; tar.len() + 1]; src.len() + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for (i, s_item) in src.iter().enumerate() {
+        for (j, t_item) in tar.iter().enumerate() {
+            let substitution_cost = if s_item == t_item { 0 } else { 1 };
+            let operations = [
+                matrix[i][j + 1] + 1,             // deletion
+                matrix[i + 1][j] + 1,             // insertion
+                matrix[i][j] + substitution_cost, // substitution
+            ];
+
+            matrix[i + 1][j + 1] = *operations.iter().min().unwrap();
+
+            // transposition
+            if i > 0 && j > 0 && *s_item == tar[j - 1] && *t_item == src[i - 1] {
+                matrix[i + 1][j + 1] = min(
+                    matrix[i + 1][j + 1],                     // cost without swappping
+                    matrix[i - 1][j - 1] + substitution_cost, // cost with swapping
+                );
+            }
+        }
+    }
+
+    matrix
+}
+
+fn restricted_distance_within<T: Eq>(src: &[T], tar: &[T], max: usize) -> Option<usize> {
+    let (src_len, tar_len) = (src.len(), tar.len());
+    if src_len.abs_diff(tar_len) > max {
+        return None;
+    }
+
+    // every value above `max` is stored as `max + 1`
+    let exceeded = max + 1;
+    let mut before_previous = vec![exceeded; tar_len + 1];
+    let mut previous: Vec<usize> = (0..=tar_len).map(|j| j.min(exceeded)).collect();
+    let mut current = vec![exceeded; tar_len + 1];
+
+    for i in 1..=src_len {
+        // only the cells at most `max` diagonals away can hold a value within the bound
+        let start = if i > max { i - max } else { 1 };
+        let end = (i + max).min(tar_len);
+
+        current[0] = i.min(exceeded);
+        current[start - 1] = if start == 1 { current[0] } else { exceeded };
+        let mut row_min = current[start - 1];
+
+        for j in start..=end {
+            let substitution_cost = if src[i - 1] == tar[j - 1] { 0 } else { 1 };
+            let operations = [
+                previous[j] + 1,                     // deletion
+                current[j - 1] + 1,                  // insertion
+                previous[j - 1] + substitution_cost, // substitution
+            ];
+            current[j] = *operations.iter().min().unwrap();
+
+            // transposition
+            if i > 1 && j > 1 && src[i - 1] == tar[j - 2] && src[i - 2] == tar[j - 1] {
+                current[j] = min(current[j], before_previous[j - 2] + substitution_cost);
+            }
+            current[j] = min(current[j], exceeded);
+            row_min = min(row_min, current[j]);
+        }
+        if end < tar_len {
+            current[end + 1] = exceeded;
+        }
+
+        // a transposition can't do better than a substitution in this row, so no later row can recover
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[tar_len]).filter(|&distance| distance <= max)
+}
+
+fn restricted_edit_script<T: Eq>(src: &[T], tar: &[T]) -> Vec<EditOperation> {
+    let matrix = restricted_matrix(src, tar);
+
+    let mut operations = Vec::new();
+    let (mut i, mut j) = (src.len(), tar.len());
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let substitution_cost = if src[i - 1] == tar[j - 1] { 0 } else { 1 };
+            if matrix[i][j] == matrix[i - 1][j - 1] + substitution_cost {
+                operations.push(keep_or_substitute(i - 1, j - 1, substitution_cost));
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+            if i > 1
+                && j > 1
+                && src[i - 1] == tar[j - 2]
+                && src[i - 2] == tar[j - 1]
+                && matrix[i][j] == matrix[i - 2][j - 2] + substitution_cost
+            {
+                operations.push(EditOperation::Transpose {
+                    src_pos: i - 2,
+                    tar_pos: j - 2,
+                });
+                i -= 2;
+                j -= 2;
+                continue;
+            }
+        }
+        if i > 0 && matrix[i][j] == matrix[i - 1][j] + 1 {
+            operations.push(EditOperation::Delete { src_pos: i - 1 });
+            i -= 1;
+        } else {
+            operations.push(EditOperation::Insert { tar_pos: j - 1 });
+            j -= 1;
+        }
+    }
+    operations.reverse();
+
+    operations
+}
+
+fn unrestricted_distance(alphabet: &Alphabet) -> usize {
+    unrestricted_matrix(alphabet)[alphabet.src.len() + 1][alphabet.tar.len() + 1]
+}
+
+// The matrix is shifted by one row and one column: the distance between
+// `src[..i]` and `tar[..j]` is stored in `matrix[i + 1][j + 1]`.
+// It takes O(n·m) time: the last row of each character is looked up by its index in the alphabet.
+fn unrestricted_matrix(alphabet: &Alphabet) -> Vec<Vec<usize>> {
+    let (src, tar) = (&alphabet.src, &alphabet.tar);
+    let src_len = src.len();
+    let tar_len = tar.len();
+
+    let max_dist = src_len + tar_len;
+
+    // last row where each character of the alphabet was seen in the source
+    let mut da: Vec<usize> = vec![0; alphabet.len];
+    let mut matrix: Vec<Vec<usize>> = vec![vec![0; tar_len + 2]; src_len + 2];
+    matrix[0][0] = max_dist;
+
+    for i in 0..(src_len + 1) {
+        matrix[i + 1][0] = max_dist;
+        matrix[i + 1][1] = i;
+    }
+
+    for j in 0..(tar_len + 1) {
+        matrix[0][j + 1] = max_dist;
+        matrix[1][j + 1] = j;
+    }
+
+    for i in 1..(src_len + 1) {
+        let mut db = 0;
+        for j in 1..(tar_len + 1) {
+            let k = da[tar[j - 1]];
+            let l = db;
+
+            let mut substitution_cost = 1;
+            if src[i - 1] == tar[j - 1] {
+                substitution_cost = 0;
+                db = j;
+            }
+            let operations = [
+                matrix[i][j] + substitution_cost,             // substitution
+                matrix[i + 1][j] + 1,                         // insertion
+                matrix[i][j + 1] + 1,                         // deletion
+                matrix[k][l] + (i - k - 1) + 1 + (j - l - 1), // transposition
+            ];
+            matrix[i + 1][j + 1] = *operations.iter().min().unwrap();
+        }
+        da[src[i - 1]] = i;
+    }
+
+    matrix
+}
+
+fn unrestricted_edit_script(alphabet: &Alphabet) -> Vec<EditOperation> {
+    let matrix = unrestricted_matrix(alphabet);
+    let (src, tar) = (&alphabet.src, &alphabet.tar);
+
+    let mut operations = Vec::new();
+    let (mut i, mut j) = (src.len(), tar.len());
+    while i > 0 || j > 0 {
+        let current = matrix[i + 1][j + 1];
+        if i > 0 && j > 0 {
+            let substitution_cost = if src[i - 1] == tar[j - 1] { 0 } else { 1 };
+            if current == matrix[i][j] + substitution_cost {
+                operations.push(keep_or_substitute(i - 1, j - 1, substitution_cost));
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+
+            // the last rows and columns where the swapped elements occur,
+            // the same `k` and `l` the matrix was filled with
+            let k = (1..i).rev().find(|&k| src[k - 1] == tar[j - 1]);
+            let l = (1..j).rev().find(|&l| tar[l - 1] == src[i - 1]);
+            if let (Some(k), Some(l)) = (k, l) {
+                if current == matrix[k][l] + (i - k - 1) + 1 + (j - l - 1) {
+                    operations.extend((l..j - 1).rev().map(|tar_pos| EditOperation::Insert { tar_pos }));
+                    operations.extend((k..i - 1).rev().map(|src_pos| EditOperation::Delete { src_pos }));
+                    operations.push(EditOperation::Transpose {
+                        src_pos: k - 1,
+                        tar_pos: l - 1,
+                    });
+                    i = k - 1;
+                    j = l - 1;
+                    continue;
+                }
+            }
+        }
+        if i > 0 && current == matrix[i][j + 1] + 1 {
+            operations.push(EditOperation::Delete { src_pos: i - 1 });
+            i -= 1;
+        } else {
+            operations.push(EditOperation::Insert { tar_pos: j - 1 });
+            j -= 1;
+        }
+    }
+    operations.reverse();
+
+    operations
+}
+
+fn keep_or_substitute(src_pos: usize, tar_pos: usize, substitution_cost: usize) -> EditOperation {
+    if substitution_cost == 0 {
+        EditOperation::Keep { src_pos, tar_pos }
+    } else {
+        EditOperation::Substitute { src_pos, tar_pos }
+    }
+}
+
+/// Calculate the `Damerau-Levenshtein` distance between two sequences of arbitrary elements.
+/// It is the same algorithm as [`DamerauLevenshtein::distance`], applied to words, token ids, bytes
+/// or any other `Eq + Hash` items instead of characters.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_damerau_levenshtein;
+///
+/// let src = [3, 1, 4, 1, 5];
+/// let tar = [1, 3, 4, 1, 5];
+///
+/// assert_eq!(1, generic_damerau_levenshtein(&src, &tar, true));
+/// assert_eq!(2, generic_damerau_levenshtein(b"ca", b"abc", false));
+///
+/// ```
+pub fn generic_damerau_levenshtein<T: Eq + Hash>(src: &[T], tar: &[T], restricted: bool) -> usize {
+    if restricted {
+        bit_parallel_distance(src, tar, true)
+    } else {
+        unrestricted_distance(&Alphabet::new(src, tar))
+    }
+}
+
+/// Calculate the `Damerau-Levenshtein` distance between two sequences of arbitrary elements if it is at most `max`.
+/// It is the same algorithm as [`DamerauLevenshtein::distance_within`], applied to arbitrary elements.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_damerau_levenshtein_within;
+///
+/// assert_eq!(Some(1), generic_damerau_levenshtein_within(&[3, 1, 4, 1, 5], &[1, 3, 4, 1, 5], true, 1));
+/// assert_eq!(None, generic_damerau_levenshtein_within(b"ca", b"abc", true, 2));
+/// assert_eq!(Some(2), generic_damerau_levenshtein_within(b"ca", b"abc", false, 2));
+///
+/// ```
+pub fn generic_damerau_levenshtein_within<T: Eq + Hash>(
+    src: &[T],
+    tar: &[T],
+    restricted: bool,
+    max: usize,
+) -> Option<usize> {
+    if restricted {
+        restricted_distance_within(src, tar, max)
+    } else if src.len().abs_diff(tar.len()) > max {
+        None
+    } else {
+        Some(unrestricted_distance(&Alphabet::new(src, tar))).filter(|&distance| distance <= max)
+    }
+}
+
+/// Find the edit operations which transform `src` into `tar` with the `Damerau-Levenshtein` distance.
+/// It is the same algorithm as [`DamerauLevenshtein::edit_script`], applied to arbitrary elements.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{generic_damerau_levenshtein_edit_script, EditOperation};
+///
+/// assert_eq!(
+///     vec![
+///         EditOperation::Transpose {src_pos: 0, tar_pos: 0},
+///         EditOperation::Keep {src_pos: 2, tar_pos: 2},
+///     ],
+///     generic_damerau_levenshtein_edit_script(&[3, 1, 4], &[1, 3, 4], true)
+/// );
+///
+/// ```
+pub fn generic_damerau_levenshtein_edit_script<T: Eq + Hash>(
+    src: &[T],
+    tar: &[T],
+    restricted: bool,
+) -> Vec<EditOperation> {
+    if restricted {
+        restricted_edit_script(src, tar)
+    } else {
+        unrestricted_edit_script(&Alphabet::new(src, tar))
+    }
+}
+
+impl<S: AsRef<str>> StringMetric for DamerauLevenshtein<S> {
+    type Output = usize;
+
+    fn distance(&self) -> usize {
+        DamerauLevenshtein::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        DamerauLevenshtein::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> usize {
+        DamerauLevenshtein::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        DamerauLevenshtein::normalized_similarity(self)
+    }
 }
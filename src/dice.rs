@@ -0,0 +1,62 @@
+use crate::set_metric::{empty_set_distance, set_metric, set_sizes};
+use crate::tokenizer::TOKENIZE_ERROR;
+use std::hash::Hash;
+
+set_metric! {
+    /// Calculate the `Sørensen–Dice` coefficient between two sets of tokens.
+    /// The Dice coefficient is twice the size of the intersection divided by the sum of the sizes of the sets.
+    /// Two empty sets have coefficient 1.0.
+    /// [For more information see wikipedia article](https://en.wikipedia.org/wiki/S%C3%B8rensen%E2%80%93Dice_coefficient)
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Dice;
+    ///
+    /// let dice = Dice {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0};
+    ///
+    /// assert_eq!(0.3846153846153846, dice.distance());
+    /// assert_eq!(0.3846153846153846, dice.normalized_distance());
+    /// assert_eq!(0.6153846153846154, dice.similarity());
+    /// assert_eq!(0.6153846153846154, dice.normalized_similarity());
+    ///
+    /// ```
+    ///
+    /// Bigrams of chars:
+    ///
+    /// ```
+    /// use text_distance::Dice;
+    ///
+    /// let dice = Dice {src: "night",  tar: "nacht", qval: 2};
+    ///
+    /// assert_eq!(0.75, dice.distance());
+    ///
+    /// ```
+    ///
+    pub struct Dice {}
+    metric: "Dice",
+    coefficient: "Dice coefficient",
+    expect: TOKENIZE_ERROR,
+    errors: [],
+    distance: |_, src_tokens, tar_tokens| Ok(generic_dice(&src_tokens, &tar_tokens)),
+}
+
+/// Calculate the `Dice` distance between two collections of arbitrary tokens.
+/// Both collections are treated as sets, so repeated tokens are counted once.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_dice;
+///
+/// assert_eq!(0.6, generic_dice(&[1, 2, 3], &[3, 4]));
+///
+/// ```
+pub fn generic_dice<T: Eq + Hash>(src: &[T], tar: &[T]) -> f64 {
+    let (src_len, tar_len, intersection_len) = set_sizes(src, tar);
+    if let Some(distance) = empty_set_distance(src_len, tar_len) {
+        return distance;
+    }
+
+    1.0 - (2 * intersection_len) as f64 / (src_len + tar_len) as f64
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+
+/// The error type returned by the `try_*` methods of the algorithms.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Error, Hamming};
+///
+/// let hamming = Hamming {src: "test",  tar: "textt"};
+///
+/// assert_eq!(Err(Error::LengthMismatch {src_len: 4, tar_len: 5}), hamming.try_distance());
+///
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The algorithm is only defined for inputs of equal length.
+    LengthMismatch {
+        /// Length of the source
+        src_len: usize,
+        /// Length of the target
+        tar_len: usize,
+    },
+    /// The input is shorter than the algorithm requires.
+    InputTooShort {
+        /// Length of the input
+        len: usize,
+        /// Minimum length accepted by the algorithm
+        min_len: usize,
+    },
+    /// A parameter of the algorithm has an invalid value.
+    InvalidParameter {
+        /// Name of the parameter
+        name: &'static str,
+        /// Why the value was rejected
+        reason: String,
+    },
+    /// The text could not be parsed.
+    Parse {
+        /// Line number, starting from 1
+        line: usize,
+        /// What is wrong with the line
+        reason: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::LengthMismatch { src_len, tar_len } => write!(
+                f,
+                "inputs must have equal length, got {} and {}",
+                src_len, tar_len
+            ),
+            Error::InputTooShort { len, min_len } => write!(
+                f,
+                "input of length {} is shorter than the required length {}",
+                len, min_len
+            ),
+            Error::InvalidParameter { name, reason } => {
+                write!(f, "invalid value of `{}`: {}", name, reason)
+            }
+            Error::Parse { line, reason } => write!(f, "line {}: {}", line, reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
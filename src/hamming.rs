@@ -1,7 +1,11 @@
+use crate::{Error, StringMetric};
 use std::cmp::max;
 
+const LENGTH_MISMATCH: &str = "Hamming distance is only defined for strings of equal length";
+
 /// Calculate the `Hamming` distance between two strings of equal length.
 /// The Hamming distance is the number of differing items in ordered sequences.
+/// For bit strings such as fingerprints see [`generic_bit_hamming`](crate::generic_bit_hamming).
 /// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Hamming_distance)
 ///
 /// ### Examples
@@ -18,14 +22,25 @@ use std::cmp::max;
 ///
 /// ```
 ///
-pub struct Hamming {
+/// `src` and `tar` can be any `AsRef<str>`, e.g. `&str`:
+///
+/// ```
+/// use text_distance::Hamming;
+///
+/// let hamming = Hamming {src: "karolin",  tar: "kathrin"};
+///
+/// assert_eq!(3, hamming.distance());
+///
+/// ```
+///
+pub struct Hamming<S = String> {
     /// Source string
-    pub src: String,
+    pub src: S,
     /// Target string
-    pub tar: String,
+    pub tar: S,
 }
 
-impl Hamming {
+impl<S: AsRef<str>> Hamming<S> {
     /// Calculate the `Hamming` distance between two strings of equal length.
     /// If not equal length, then panic.
     ///
@@ -40,19 +55,29 @@ impl Hamming {
     ///
     /// ```
     pub fn distance(&self) -> usize {
-        if self.src.chars().count() != self.tar.chars().count() {
-            panic!("Hamming distance is only defined for strings of equal length");
-        }
-
-        let mut distance_counter = 0;
+        self.try_distance().expect(LENGTH_MISMATCH)
+    }
 
-        for (s_char, t_char) in self.src.chars().zip(self.tar.chars()) {
-            if s_char != t_char {
-                distance_counter += 1;
-            }
-        }
+    /// Calculate the `Hamming` distance between two strings of equal length.
+    /// If not equal length, then return [`Error::LengthMismatch`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{Error, Hamming};
+    ///
+    /// let hamming = Hamming {src: "karolin".to_string(),  tar: "kathrin".to_string()};
+    /// let mismatch = Hamming {src: "test".to_string(),  tar: "textt".to_string()};
+    ///
+    /// assert_eq!(Ok(3), hamming.try_distance());
+    /// assert_eq!(Err(Error::LengthMismatch {src_len: 4, tar_len: 5}), mismatch.try_distance());
+    ///
+    /// ```
+    pub fn try_distance(&self) -> Result<usize, Error> {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
 
-        distance_counter
+        try_generic_hamming(&src, &tar)
     }
 
     /// Calculate the `normalized distance` between two strings.
@@ -72,16 +97,33 @@ impl Hamming {
     ///
     /// ```
     pub fn normalized_distance(&self) -> f64 {
+        self.try_normalized_distance().expect(LENGTH_MISMATCH)
+    }
+
+    /// Calculate the `normalized distance` between two strings.
+    /// If not equal length, then return [`Error::LengthMismatch`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Hamming;
+    ///
+    /// let hamming = Hamming {src: "karolin".to_string(),  tar: "kathrin".to_string()};
+    ///
+    /// assert_eq!(Ok(0.42857142857142855), hamming.try_normalized_distance());
+    ///
+    /// ```
+    pub fn try_normalized_distance(&self) -> Result<f64, Error> {
         let maximum = max(
-            self.src.clone().chars().count(),
-            self.tar.clone().chars().count(),
+            self.src.as_ref().chars().count(),
+            self.tar.as_ref().chars().count(),
         );
-        let str_distance = self.distance();
+        let str_distance = self.try_distance()?;
         if maximum != 0 {
-            return (str_distance as f64) / (maximum as f64);
+            return Ok((str_distance as f64) / (maximum as f64));
         }
 
-        0.0
+        Ok(0.0)
     }
 
     /// Calculate the `similarity` between two strings.
@@ -101,13 +143,30 @@ impl Hamming {
     ///
     /// ```
     pub fn similarity(&self) -> usize {
+        self.try_similarity().expect(LENGTH_MISMATCH)
+    }
+
+    /// Calculate the `similarity` between two strings.
+    /// If not equal length, then return [`Error::LengthMismatch`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Hamming;
+    ///
+    /// let hamming = Hamming {src: "karolin".to_string(),  tar: "kathrin".to_string()};
+    ///
+    /// assert_eq!(Ok(4), hamming.try_similarity());
+    ///
+    /// ```
+    pub fn try_similarity(&self) -> Result<usize, Error> {
         let maximum = max(
-            self.src.clone().chars().count(),
-            self.tar.clone().chars().count(),
+            self.src.as_ref().chars().count(),
+            self.tar.as_ref().chars().count(),
         );
-        let str_distance = self.distance();
+        let str_distance = self.try_distance()?;
 
-        maximum - str_distance
+        Ok(maximum - str_distance)
     }
 
     /// Calculate the `normalized similarity` between two strings.
@@ -127,8 +186,108 @@ impl Hamming {
     ///
     /// ```
     pub fn normalized_similarity(&self) -> f64 {
-        let str_normalized_distance = self.normalized_distance();
+        self.try_normalized_similarity().expect(LENGTH_MISMATCH)
+    }
+
+    /// Calculate the `normalized similarity` between two strings.
+    /// If not equal length, then return [`Error::LengthMismatch`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Hamming;
+    ///
+    /// let hamming = Hamming {src: "karolin".to_string(),  tar: "kathrin".to_string()};
+    ///
+    /// assert_eq!(Ok(0.5714285714285714), hamming.try_normalized_similarity());
+    ///
+    /// ```
+    pub fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        let str_normalized_distance = self.try_normalized_distance()?;
+
+        Ok(1.0 - str_normalized_distance)
+    }
+}
+
+/// Calculate the `Hamming` distance between two sequences of arbitrary elements of equal length.
+/// If not equal length, then panic.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_hamming;
+///
+/// assert_eq!(2, generic_hamming(&[1, 0, 1, 1], &[1, 1, 1, 0]));
+/// assert_eq!(3, generic_hamming(b"karolin", b"kathrin"));
+///
+/// ```
+pub fn generic_hamming<T: PartialEq>(src: &[T], tar: &[T]) -> usize {
+    try_generic_hamming(src, tar).expect(LENGTH_MISMATCH)
+}
+
+/// Calculate the `Hamming` distance between two sequences of arbitrary elements of equal length.
+/// If not equal length, then return [`Error::LengthMismatch`].
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{try_generic_hamming, Error};
+///
+/// assert_eq!(Ok(2), try_generic_hamming(&[1, 0, 1, 1], &[1, 1, 1, 0]));
+/// assert_eq!(Err(Error::LengthMismatch {src_len: 2, tar_len: 3}), try_generic_hamming(&[1, 0], &[1, 0, 1]));
+///
+/// ```
+pub fn try_generic_hamming<T: PartialEq>(src: &[T], tar: &[T]) -> Result<usize, Error> {
+    if src.len() != tar.len() {
+        return Err(Error::LengthMismatch {
+            src_len: src.len(),
+            tar_len: tar.len(),
+        });
+    }
+
+    let mut distance_counter = 0;
+
+    for (s_item, t_item) in src.iter().zip(tar.iter()) {
+        if s_item != t_item {
+            distance_counter += 1;
+        }
+    }
+
+    Ok(distance_counter)
+}
+
+impl<S: AsRef<str>> StringMetric for Hamming<S> {
+    type Output = usize;
+
+    fn distance(&self) -> usize {
+        Hamming::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        Hamming::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> usize {
+        Hamming::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        Hamming::normalized_similarity(self)
+    }
+
+    fn try_distance(&self) -> Result<usize, Error> {
+        Hamming::try_distance(self)
+    }
+
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        Hamming::try_normalized_distance(self)
+    }
+
+    fn try_similarity(&self) -> Result<usize, Error> {
+        Hamming::try_similarity(self)
+    }
 
-        1.0 - str_normalized_distance
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        Hamming::try_normalized_similarity(self)
     }
 }
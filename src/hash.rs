@@ -0,0 +1,47 @@
+// Deterministic hashing for MinHash signatures and LSH buckets.
+//
+// `std::collections::hash_map::DefaultHasher` is randomly seeded and may change between Rust
+// releases, so signatures computed by different processes couldn't be compared. FNV-1a and
+// SplitMix64 are small, fixed and good enough to spread tokens over 64 bits.
+
+use std::hash::{Hash, Hasher};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// The 64-bit FNV-1a hash.
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(crate) fn new() -> Self {
+        Fnv1a(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Hash a value with FNV-1a.
+pub(crate) fn fnv1a<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv1a::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The SplitMix64 finalizer, which maps every input to a well mixed output (it is a bijection).
+pub(crate) fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
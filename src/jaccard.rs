@@ -1,7 +1,12 @@
+use crate::set_metric::empty_set_distance;
+use crate::tokenizer::TOKENIZE_ERROR;
+use crate::{Error, StringMetric, Tokenizer};
 use std::collections::HashSet;
+use std::hash::Hash;
 
 /// Calculate the `Jaccard` index between two sets.
 /// The Jaccard index between two words/chars/ngrams is the intersection divided by the union.
+/// Two empty sets have index 1.0.
 /// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Jaccard_index)
 ///
 /// ### Examples
@@ -18,47 +23,41 @@ use std::collections::HashSet;
 ///
 /// ```
 ///
-pub struct Jaccard {
+/// The texts can also be borrowed:
+///
+/// ```
+/// use text_distance::Jaccard;
+///
+/// let jaccard = Jaccard {src: "karolin",  tar: "kathrin", qval: 1};
+///
+/// assert_eq!(0.4444444444444444, jaccard.distance());
+///
+/// ```
+///
+/// Any [`Tokenizer`] can be used instead of `qval`, e.g. to ignore punctuation:
+///
+/// ```
+/// use text_distance::{Jaccard, Words};
+///
+/// let jaccard = Jaccard {src: "Hello, world!",  tar: "hello world", qval: Words::default()};
+///
+/// assert_eq!(0.6666666666666667, jaccard.distance());
+///
+/// ```
+///
+pub struct Jaccard<S = String, Q = usize> {
     /// Source string
-    pub src: String,
+    pub src: S,
     /// Target string
-    pub tar: String,
-    /// q-gram value.
+    pub tar: S,
+    /// q-gram value, or any other [`Tokenizer`].
     /// * If `qval = 0` then q-grams are words
     /// * If `qval = 1` then q-grams are chars
-    /// * If `qval > 1` then q-grams are ngrams
-    pub qval: usize,
+    /// * If `qval > 1` then q-grams are ngrams of `qval` chars
+    pub qval: Q,
 }
 
-impl Jaccard {
-    // TODO: https://stackoverflow.com/a/51261570
-    fn tokenize(&self, text: &String) -> HashSet<String> {
-        match self.qval {
-            // by words
-            0 => {
-                let tokens: HashSet<String> = text.split_whitespace().map(String::from).collect();
-                tokens
-            }
-            // by chars
-            1 => {
-                let tokens: HashSet<String> = text.chars().map(String::from).collect();
-                tokens
-            }
-            // by ngrams
-            _ => {
-                if text.chars().count() < self.qval {
-                    panic!("Can't create n-grams from text shorter than n-gram length")
-                }
-                let tokens: HashSet<String> = text
-                    .as_bytes()
-                    .windows(self.qval)
-                    .map(|ngram| String::from_utf8_lossy(ngram).to_string())
-                    .collect();
-                tokens
-            }
-        }
-    }
-
+impl<S: AsRef<str>, Q: Tokenizer> Jaccard<S, Q> {
     /// Calculate the `Jaccard` distance between two strings.
     /// The Jaccard distance is 1 minus Jaccard index.
     /// In other words it is 1 minus intersection over union.
@@ -78,15 +77,29 @@ impl Jaccard {
     /// ```
     ///
     pub fn distance(&self) -> f64 {
-        let src_tokens = self.tokenize(&self.src);
-        let tar_tokens = self.tokenize(&self.tar);
+        self.try_distance().expect(TOKENIZE_ERROR)
+    }
 
-        let tokens_intersection: HashSet<&String> = src_tokens.intersection(&tar_tokens).collect();
-        let tokens_union: HashSet<&String> = src_tokens.union(&tar_tokens).collect();
-        // let tokens_intersection = src_tokens.intersection(&tar_tokens).collect::<HashSet<&String>>();
-        // let tokens_union = src_tokens.union(&tar_tokens).collect::<HashSet<&String>>();
+    /// Calculate the `Jaccard` distance between two strings.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{Error, Jaccard};
+    ///
+    /// let jaccard = Jaccard {src: "karolin".to_string(),  tar: "kathrin".to_string(), qval: 1};
+    /// let too_short = Jaccard {src: "karolin".to_string(),  tar: "kathrin".to_string(), qval: 10};
+    ///
+    /// assert_eq!(Ok(0.4444444444444444), jaccard.try_distance());
+    /// assert_eq!(Err(Error::InputTooShort {len: 7, min_len: 10}), too_short.try_distance());
+    ///
+    /// ```
+    pub fn try_distance(&self) -> Result<f64, Error> {
+        let src_tokens = self.qval.tokenize(self.src.as_ref())?;
+        let tar_tokens = self.qval.tokenize(self.tar.as_ref())?;
 
-        1.0 - (tokens_intersection.len() as f64 / tokens_union.len() as f64)
+        Ok(generic_jaccard(&src_tokens, &tar_tokens))
     }
 
     /// Calculate the `normalized distance`.
@@ -106,9 +119,26 @@ impl Jaccard {
     ///
     /// ```
     pub fn normalized_distance(&self) -> f64 {
-        let str_distance = self.distance();
+        self.try_normalized_distance().expect(TOKENIZE_ERROR)
+    }
 
-        str_distance / 1.0
+    /// Calculate the `normalized distance`.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use text_distance::Jaccard;
+    ///
+    /// let jaccard = Jaccard {src: "karolin".to_string(),  tar: "kathrin".to_string(), qval: 1};
+    ///
+    /// assert_eq!(Ok(0.4444444444444444), jaccard.try_normalized_distance());
+    ///
+    /// ```
+    pub fn try_normalized_distance(&self) -> Result<f64, Error> {
+        let str_distance = self.try_distance()?;
+
+        Ok(str_distance / 1.0)
     }
 
     /// Calculate the `similarity`.
@@ -128,9 +158,26 @@ impl Jaccard {
     ///
     /// ```
     pub fn similarity(&self) -> f64 {
-        let str_distance = self.distance();
+        self.try_similarity().expect(TOKENIZE_ERROR)
+    }
+
+    /// Calculate the `similarity`.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use text_distance::Jaccard;
+    ///
+    /// let jaccard = Jaccard {src: "karolin".to_string(),  tar: "kathrin".to_string(), qval: 1};
+    ///
+    /// assert_eq!(Ok(0.5555555555555556), jaccard.try_similarity());
+    ///
+    /// ```
+    pub fn try_similarity(&self) -> Result<f64, Error> {
+        let str_distance = self.try_distance()?;
 
-        1.0 - str_distance
+        Ok(1.0 - str_distance)
     }
 
     /// Calculate the `normalized similarity`.
@@ -151,8 +198,90 @@ impl Jaccard {
     /// ```
     ///
     pub fn normalized_similarity(&self) -> f64 {
-        let str_normalized_distance = self.normalized_distance();
+        self.try_normalized_similarity().expect(TOKENIZE_ERROR)
+    }
+
+    /// Calculate the `normalized similarity`.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use text_distance::Jaccard;
+    ///
+    /// let jaccard = Jaccard {src: "karolin".to_string(),  tar: "kathrin".to_string(), qval: 1};
+    ///
+    /// assert_eq!(Ok(0.5555555555555556), jaccard.try_normalized_similarity());
+    ///
+    /// ```
+    pub fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        let str_normalized_distance = self.try_normalized_distance()?;
+
+        Ok(1.0 - str_normalized_distance)
+    }
+}
+
+/// Calculate the `Jaccard` distance between two collections of arbitrary tokens.
+/// Both collections are treated as sets, so repeated tokens are counted once.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_jaccard;
+///
+/// assert_eq!(0.5, generic_jaccard(&[101, 2023, 2003], &[101, 2023, 2062]));
+/// assert_eq!(0.4444444444444444, generic_jaccard(b"karolin", b"kathrin"));
+///
+/// ```
+pub fn generic_jaccard<T: Eq + Hash>(src: &[T], tar: &[T]) -> f64 {
+    let src_tokens: HashSet<&T> = src.iter().collect();
+    let tar_tokens: HashSet<&T> = tar.iter().collect();
+
+    jaccard_of_sets(&src_tokens, &tar_tokens)
+}
+
+pub(crate) fn jaccard_of_sets<T: Eq + Hash>(src_tokens: &HashSet<T>, tar_tokens: &HashSet<T>) -> f64 {
+    if let Some(distance) = empty_set_distance(src_tokens.len(), tar_tokens.len()) {
+        return distance;
+    }
+    let intersection_len = src_tokens.intersection(tar_tokens).count();
+    let union_len = src_tokens.len() + tar_tokens.len() - intersection_len;
+
+    1.0 - (intersection_len as f64 / union_len as f64)
+}
+
+impl<S: AsRef<str>, Q: Tokenizer> StringMetric for Jaccard<S, Q> {
+    type Output = f64;
+
+    fn distance(&self) -> f64 {
+        Jaccard::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        Jaccard::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> f64 {
+        Jaccard::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        Jaccard::normalized_similarity(self)
+    }
+
+    fn try_distance(&self) -> Result<f64, Error> {
+        Jaccard::try_distance(self)
+    }
+
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        Jaccard::try_normalized_distance(self)
+    }
+
+    fn try_similarity(&self) -> Result<f64, Error> {
+        Jaccard::try_similarity(self)
+    }
 
-        1.0 - str_normalized_distance
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        Jaccard::try_normalized_similarity(self)
     }
 }
@@ -1,8 +1,103 @@
+use crate::cost::is_valid_cost;
+use crate::{Error, StringMetric};
 use std::cmp::{max, min};
 
+const INVALID_PARAMS: &str =
+    "Jaro-Winkler parameters must not be negative, the prefix scale times the prefix cap must not exceed 1, and the boost threshold must be between 0 and 1";
+
+/// Parameters of the `Winkler` modification of the `Jaro` similarity.
+/// If the `Jaro` similarity `sim` is greater than `boost_threshold`, then it is increased to
+/// `sim + prefix_len * prefix_scale * (1 - sim)`, where `prefix_len` is the length of the common prefix,
+/// but at most `max_prefix_len`.
+/// The similarity stays at most 1 only if `prefix_scale * max_prefix_len <= 1`, which is validated.
+/// The default parameters are the ones proposed by Winkler: 0.1, 4 and 0.7.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{JaroWinkler, JaroWinklerParams};
+///
+/// let jaro_winkler = JaroWinkler {src: "karolin",  tar: "kathrin", winklerize: true};
+/// let params = JaroWinklerParams {prefix_scale: 0.2, ..JaroWinklerParams::default()};
+///
+/// assert_eq!(jaro_winkler.distance(), jaro_winkler.distance_with(&JaroWinklerParams::default()));
+/// assert_eq!(0.11428571428571421, jaro_winkler.distance_with(&params));
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JaroWinklerParams {
+    /// How much each character of the common prefix increases the similarity
+    pub prefix_scale: f64,
+    /// The longest common prefix which is taken into account
+    pub max_prefix_len: usize,
+    /// Only similarities greater than the threshold are increased
+    pub boost_threshold: f64,
+}
+
+impl JaroWinklerParams {
+    /// Create parameters from the prefix scale, the prefix cap and the boost threshold.
+    pub fn new(prefix_scale: f64, max_prefix_len: usize, boost_threshold: f64) -> Self {
+        JaroWinklerParams {
+            prefix_scale,
+            max_prefix_len,
+            boost_threshold,
+        }
+    }
+
+    /// Check that `prefix_scale` is not negative, `prefix_scale * max_prefix_len <= 1`
+    /// and `boost_threshold` is between 0.0 and 1.0.
+    /// Otherwise return [`Error::InvalidParameter`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::JaroWinklerParams;
+    ///
+    /// assert!(JaroWinklerParams::new(0.25, 4, 0.7).validate().is_ok());
+    /// assert!(JaroWinklerParams::new(0.25, 5, 0.7).validate().is_err());
+    ///
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        if !is_valid_cost(self.prefix_scale) {
+            return Err(Error::InvalidParameter {
+                name: "prefix_scale",
+                reason: format!("must not be negative, got {:?}", self.prefix_scale),
+            });
+        }
+        // an infinite scale with a cap of 0 gives NaN
+        let max_boost = self.prefix_scale * self.max_prefix_len as f64;
+        if max_boost.is_nan() || max_boost > 1.0 {
+            return Err(Error::InvalidParameter {
+                name: "prefix_scale",
+                reason: format!(
+                    "prefix_scale * max_prefix_len must not exceed 1, got {:?} * {}",
+                    self.prefix_scale, self.max_prefix_len
+                ),
+            });
+        }
+        if !(0.0..=1.0).contains(&self.boost_threshold) {
+            return Err(Error::InvalidParameter {
+                name: "boost_threshold",
+                reason: format!("must be between 0 and 1, got {:?}", self.boost_threshold),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for JaroWinklerParams {
+    fn default() -> Self {
+        JaroWinklerParams::new(0.1, 4, 0.7)
+    }
+}
+
 /// Calculate the `Jaro` or `Jaro-Winkler` similarity between two strings.
 /// The `Jaro-Winkler` similarity is the modification of the `Jaro` similarity.
 /// It increases the score if the characters at the start of both strings are the same.
+/// Strings are compared by `char`s, so e.g. Georgian or Cyrillic letters count as one character each,
+/// but a letter with a combining mark counts as two.
+/// See [`Strcmp95`](crate::Strcmp95) for the variant with credit for similar characters used in record linkage.
 /// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
 ///
 /// ### Examples
@@ -19,98 +114,54 @@ use std::cmp::{max, min};
 ///
 /// ```
 /// 
-pub struct JaroWinkler {
+/// Using borrowed strings:
+///
+/// ```
+/// use text_distance::JaroWinkler;
+///
+/// let jaro_winkler = JaroWinkler {src: "karolin",  tar: "kathrin", winklerize: true};
+///
+/// assert_eq!(0.15238095238095228, jaro_winkler.distance());
+///
+/// ```
+///
+pub struct JaroWinkler<S = String> {
     /// Source string
-    pub src: String,
+    pub src: S,
     /// Target string
-    pub tar: String,
-    /// * If `winklerize = true` it calculates `Jaro-Winkler similarity`.
+    pub tar: S,
+    /// * If `winklerize = true` it calculates `Jaro-Winkler similarity`
+    ///   with the default [`JaroWinklerParams`], see [`JaroWinkler::distance_with`] for others.
     /// * If `winklerize = false` it calculates `Jaro similarity`.
     pub winklerize: bool,
 }
 
-impl JaroWinkler {
-    fn jaro(&self) -> f64 {
-        let src_len = self.src.chars().count();
-        let tar_len = self.tar.chars().count();
-
-        if src_len == 0 && tar_len == 0 {
-            return 1.0;
-        } else if src_len == 0 || tar_len == 0 {
-            return 0.0;
-        } else if self.src == self.tar {
-            return 1.0;
-        }
-
-        let match_radius = max(src_len, tar_len) / 2 - 1;
-        let mut src_matches = vec![false; src_len];
-        let mut tar_matches = vec![false; tar_len];
-
-        let mut common_chars: usize = 0;
-
-        for (i, s_char) in self.src.chars().enumerate() {
-            let low = if i > match_radius {
-                max(0, i - match_radius) // There was overflow. Don't know why
-            } else {
-                0
-            };
-            let high = min(i + match_radius + 1, tar_len);
-
-            for (j, t_char) in self.tar.chars().enumerate().take(high).skip(low) {
-                if t_char == s_char && !tar_matches[j] {
-                    src_matches[i] = true;
-                    tar_matches[j] = true;
-                    common_chars += 1;
-                    break;
-                }
-            }
-        }
-
-        if common_chars == 0 {
-            return 0.0;
-        }
-
-        // Transpositions
-        let mut k = 0;
-        let mut transpositions = 0;
-
-        for (i, _value) in src_matches.iter().enumerate().take(src_len) {
-            if !src_matches[i] {
-                continue;
-            }
-            while !tar_matches[k] {
-                k += 1;
-            }
-            if self.src.as_bytes()[i] != self.tar.as_bytes()[k] {
-                transpositions += 1;
-            }
-            k += 1;
-        }
-
-        ((common_chars as f64 / src_len as f64)
-            + (common_chars as f64 / tar_len as f64)
-            + ((common_chars - (transpositions / 2)) as f64 / common_chars as f64))
-            / 3.0
-    }
-
-    fn winkler(&self) -> f64 {
-        let jaro_distance = self.jaro();
-        let mut prefix_len = 0;
-        if jaro_distance > 0.7 {
-            for (_i, (s_char, t_char)) in self.src.chars().zip(self.tar.chars()).enumerate() {
-                if s_char == t_char {
-                    prefix_len += 1;
-                } else {
-                    break;
-                }
-            }
-            prefix_len = min(4, prefix_len);
-            return jaro_distance + (prefix_len as f64 * 0.1 * (1.0 - jaro_distance));
-        }
-
-        jaro_distance
-    }
+/// The `Jaro` or `Jaro-Winkler` similarity of two strings with the intermediate values
+/// it is computed from, returned by [`JaroWinkler::explain`].
+/// Positions are indices of `char`s, and the n-th matched position of the source
+/// is matched with the n-th matched position of the target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JaroExplanation {
+    /// The `Jaro-Winkler` similarity if `winklerize = true`, otherwise the `Jaro` similarity
+    pub similarity: f64,
+    /// The `Jaro` similarity, before the boost for the common prefix
+    pub jaro: f64,
+    /// Number of matching characters
+    pub matches: usize,
+    /// Number of matched pairs with different characters, i.e. of matched characters out of order.
+    /// The `Jaro` similarity counts `half_transpositions / 2` transpositions, rounded down.
+    pub half_transpositions: usize,
+    /// Positions of the matching characters in the source
+    pub src_positions: Vec<usize>,
+    /// Positions of the matching characters in the target
+    pub tar_positions: Vec<usize>,
+    /// Characters match only if their positions differ by at most the radius
+    pub match_radius: usize,
+    /// Length of the common prefix which increased the similarity, 0 if it wasn't increased
+    pub prefix_len: usize,
+}
 
+impl<S: AsRef<str>> JaroWinkler<S> {
     /// Calculate the `Jaro-Winkler` distance between two strings.
     /// The parameter `winklerize` is used to determine the algorithm.
     /// If winklerize is `true`, it calculates `Jaro-Winkler` similarity.
@@ -129,13 +180,100 @@ impl JaroWinkler {
     ///
     /// ```
     pub fn distance(&self) -> f64 {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        generic_jaro_winkler(&src, &tar, self.winklerize)
+    }
+
+    /// Calculate the `Jaro-Winkler` distance between two strings with custom parameters.
+    /// If `winklerize = false`, then the parameters are only validated.
+    /// If the parameters are invalid, then panic.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{JaroWinkler, JaroWinklerParams};
+    ///
+    /// let jaro_winkler = JaroWinkler {src: "frog",  tar: "fog", winklerize: true};
+    ///
+    /// assert_eq!(0.07500000000000007, jaro_winkler.distance_with(&JaroWinklerParams::default()));
+    /// assert_eq!(0.08333333333333337, jaro_winkler.distance_with(&JaroWinklerParams::new(0.1, 4, 0.95)));
+    ///
+    /// ```
+    pub fn distance_with(&self, params: &JaroWinklerParams) -> f64 {
+        self.try_distance_with(params).expect(INVALID_PARAMS)
+    }
+
+    /// Calculate the `Jaro-Winkler` distance between two strings with custom parameters.
+    /// If the parameters are invalid, then return [`Error::InvalidParameter`].
+    pub fn try_distance_with(&self, params: &JaroWinklerParams) -> Result<f64, Error> {
+        params.validate()?;
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
         if self.winklerize {
-            1.0 - self.winkler()
+            Ok(1.0 - winkler(&src, &tar, params))
         } else {
-            1.0 - self.jaro()
+            Ok(1.0 - jaro(&src, &tar))
         }
     }
 
+    /// Calculate the `similarity` with custom parameters.
+    /// The similarity is 1 minus the distance.
+    /// If the parameters are invalid, then panic.
+    pub fn similarity_with(&self, params: &JaroWinklerParams) -> f64 {
+        self.try_similarity_with(params).expect(INVALID_PARAMS)
+    }
+
+    /// Calculate the `similarity` with custom parameters.
+    /// If the parameters are invalid, then return [`Error::InvalidParameter`].
+    pub fn try_similarity_with(&self, params: &JaroWinklerParams) -> Result<f64, Error> {
+        let str_distance = self.try_distance_with(params)?;
+
+        Ok(1.0 - str_distance)
+    }
+
+    /// Calculate the `similarity` together with the intermediate values it is computed from,
+    /// which helps to understand why two strings got a score.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::JaroWinkler;
+    ///
+    /// let explanation = JaroWinkler {src: "martha",  tar: "marhta", winklerize: true}.explain();
+    ///
+    /// assert_eq!(0.9611111111111111, explanation.similarity);
+    /// assert_eq!(0.9444444444444445, explanation.jaro);
+    /// assert_eq!(6, explanation.matches);
+    /// // "th" and "ht" are in a different order, i.e. one transposition
+    /// assert_eq!(2, explanation.half_transpositions);
+    /// assert_eq!(vec![0, 1, 2, 3, 4, 5], explanation.src_positions);
+    /// assert_eq!(2, explanation.match_radius);
+    /// assert_eq!(3, explanation.prefix_len);
+    ///
+    /// ```
+    pub fn explain(&self) -> JaroExplanation {
+        self.explain_with(&JaroWinklerParams::default())
+    }
+
+    /// Calculate the `similarity` with custom parameters together with the intermediate values.
+    /// If the parameters are invalid, then panic.
+    pub fn explain_with(&self, params: &JaroWinklerParams) -> JaroExplanation {
+        self.try_explain_with(params).expect(INVALID_PARAMS)
+    }
+
+    /// Calculate the `similarity` with custom parameters together with the intermediate values.
+    /// If the parameters are invalid, then return [`Error::InvalidParameter`].
+    pub fn try_explain_with(&self, params: &JaroWinklerParams) -> Result<JaroExplanation, Error> {
+        params.validate()?;
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        Ok(explain(&src, &tar, self.winklerize, params))
+    }
+
     /// Calculate the `normalized distance`.
     /// The normalized distance is the distance divided by the 1.
     /// The normalized distance is always between 0.0 and 1.0.
@@ -202,3 +340,237 @@ impl JaroWinkler {
         1.0 - str_normalized_distance
     }
 }
+
+fn jaro<T: PartialEq>(src: &[T], tar: &[T]) -> f64 {
+    jaro_with_buffers(src, tar, &mut Vec::new(), &mut Vec::new())
+}
+
+// The buffers for matched positions are reused by `Query` between comparisons.
+pub(crate) fn jaro_with_buffers<T: PartialEq>(
+    src: &[T],
+    tar: &[T],
+    src_matches: &mut Vec<bool>,
+    tar_matches: &mut Vec<bool>,
+) -> f64 {
+    let src_len = src.len();
+    let tar_len = tar.len();
+
+    if src_len == 0 && tar_len == 0 {
+        return 1.0;
+    } else if src_len == 0 || tar_len == 0 {
+        return 0.0;
+    } else if src == tar {
+        return 1.0;
+    }
+
+    let common_chars = find_matches(src, tar, src_matches, tar_matches);
+    if common_chars == 0 {
+        return 0.0;
+    }
+    let half_transpositions = count_half_transpositions(src, tar, src_matches, tar_matches);
+
+    jaro_of_counts(src_len, tar_len, common_chars, half_transpositions)
+}
+
+fn jaro_of_counts(src_len: usize, tar_len: usize, common_chars: usize, half_transpositions: usize) -> f64 {
+    if src_len == 0 && tar_len == 0 {
+        return 1.0;
+    } else if common_chars == 0 {
+        return 0.0;
+    }
+    // As in the reference implementations, an odd half-transposition is not counted.
+    let transpositions = half_transpositions / 2;
+
+    ((common_chars as f64 / src_len as f64)
+        + (common_chars as f64 / tar_len as f64)
+        + ((common_chars - transpositions) as f64 / common_chars as f64))
+        / 3.0
+}
+
+// Mark the matched elements of both sequences and return the number of matches.
+// Elements match if they are equal and no farther apart than the radius.
+pub(crate) fn find_matches<T: PartialEq>(
+    src: &[T],
+    tar: &[T],
+    src_matches: &mut Vec<bool>,
+    tar_matches: &mut Vec<bool>,
+) -> usize {
+    let match_radius = match_radius(src.len(), tar.len());
+    src_matches.clear();
+    src_matches.resize(src.len(), false);
+    tar_matches.clear();
+    tar_matches.resize(tar.len(), false);
+
+    let mut common_chars: usize = 0;
+
+    for (i, s_item) in src.iter().enumerate() {
+        let low = i.saturating_sub(match_radius);
+        let high = min(i + match_radius + 1, tar.len());
+
+        for j in low..high {
+            if !tar_matches[j] && tar[j] == *s_item {
+                src_matches[i] = true;
+                tar_matches[j] = true;
+                common_chars += 1;
+                break;
+            }
+        }
+    }
+
+    common_chars
+}
+
+fn match_radius(src_len: usize, tar_len: usize) -> usize {
+    // For sequences of one element the radius is 0 instead of -1.
+    (max(src_len, tar_len) / 2).saturating_sub(1)
+}
+
+// Count the matched elements which are in a different order in both sequences.
+pub(crate) fn count_half_transpositions<T: PartialEq>(
+    src: &[T],
+    tar: &[T],
+    src_matches: &[bool],
+    tar_matches: &[bool],
+) -> usize {
+    let src_matched = src.iter().zip(src_matches).filter(|(_, &matched)| matched);
+    let tar_matched = tar.iter().zip(tar_matches).filter(|(_, &matched)| matched);
+
+    src_matched
+        .zip(tar_matched)
+        .filter(|((s_item, _), (t_item, _))| s_item != t_item)
+        .count()
+}
+
+fn winkler<T: PartialEq>(src: &[T], tar: &[T], params: &JaroWinklerParams) -> f64 {
+    winkler_boost(src, tar, jaro(src, tar), params)
+}
+
+pub(crate) fn winkler_boost<T: PartialEq>(
+    src: &[T],
+    tar: &[T],
+    jaro_distance: f64,
+    params: &JaroWinklerParams,
+) -> f64 {
+    if jaro_distance > params.boost_threshold {
+        let prefix_len = common_prefix_len(src, tar, params.max_prefix_len);
+        return prefix_boost(jaro_distance, prefix_len, params);
+    }
+
+    jaro_distance
+}
+
+fn prefix_boost(jaro_distance: f64, prefix_len: usize, params: &JaroWinklerParams) -> f64 {
+    jaro_distance + (prefix_len as f64 * params.prefix_scale * (1.0 - jaro_distance))
+}
+
+fn common_prefix_len<T: PartialEq>(src: &[T], tar: &[T], max_len: usize) -> usize {
+    src.iter()
+        .zip(tar)
+        .take(max_len)
+        .take_while(|(s_item, t_item)| s_item == t_item)
+        .count()
+}
+
+fn explain<T: PartialEq>(src: &[T], tar: &[T], winklerize: bool, params: &JaroWinklerParams) -> JaroExplanation {
+    let mut src_matches = Vec::new();
+    let mut tar_matches = Vec::new();
+    let matches = find_matches(src, tar, &mut src_matches, &mut tar_matches);
+    let half_transpositions = count_half_transpositions(src, tar, &src_matches, &tar_matches);
+    let positions = |matched: &[bool]| -> Vec<usize> {
+        matched
+            .iter()
+            .enumerate()
+            .filter(|(_, &matched)| matched)
+            .map(|(i, _)| i)
+            .collect()
+    };
+
+    let jaro = jaro_of_counts(src.len(), tar.len(), matches, half_transpositions);
+    let (similarity, prefix_len) = if winklerize && jaro > params.boost_threshold {
+        let prefix_len = common_prefix_len(src, tar, params.max_prefix_len);
+        (prefix_boost(jaro, prefix_len, params), prefix_len)
+    } else {
+        (jaro, 0)
+    };
+
+    JaroExplanation {
+        similarity,
+        jaro,
+        matches,
+        half_transpositions,
+        src_positions: positions(&src_matches),
+        tar_positions: positions(&tar_matches),
+        match_radius: match_radius(src.len(), tar.len()),
+        prefix_len,
+    }
+}
+
+/// Calculate the `Jaro` or `Jaro-Winkler` distance between two sequences of arbitrary elements.
+/// It returns the same value as [`JaroWinkler::distance`], i.e. 1 minus the similarity.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_jaro_winkler;
+///
+/// let src: Vec<&str> = "john ronald reuel tolkien".split_whitespace().collect();
+/// let tar: Vec<&str> = "ronald john reuel tolkien".split_whitespace().collect();
+///
+/// assert_eq!(0.08333333333333337, generic_jaro_winkler(&src, &tar, false));
+/// assert_eq!(0.07500000000000007, generic_jaro_winkler(b"frog", b"fog", true));
+///
+/// ```
+pub fn generic_jaro_winkler<T: PartialEq>(src: &[T], tar: &[T], winklerize: bool) -> f64 {
+    if winklerize {
+        1.0 - winkler(src, tar, &JaroWinklerParams::default())
+    } else {
+        1.0 - jaro(src, tar)
+    }
+}
+
+/// Calculate the `Jaro-Winkler` distance between two sequences of arbitrary elements with custom parameters.
+/// If the parameters are invalid, then panic.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{generic_jaro_winkler_with, JaroWinklerParams};
+///
+/// assert_eq!(0.06666666666666665, generic_jaro_winkler_with(b"frog", b"fog", &JaroWinklerParams::new(0.2, 4, 0.7)));
+///
+/// ```
+pub fn generic_jaro_winkler_with<T: PartialEq>(src: &[T], tar: &[T], params: &JaroWinklerParams) -> f64 {
+    try_generic_jaro_winkler_with(src, tar, params).expect(INVALID_PARAMS)
+}
+
+/// Calculate the `Jaro-Winkler` distance between two sequences of arbitrary elements with custom parameters.
+/// If the parameters are invalid, then return [`Error::InvalidParameter`].
+pub fn try_generic_jaro_winkler_with<T: PartialEq>(
+    src: &[T],
+    tar: &[T],
+    params: &JaroWinklerParams,
+) -> Result<f64, Error> {
+    params.validate()?;
+
+    Ok(1.0 - winkler(src, tar, params))
+}
+
+impl<S: AsRef<str>> StringMetric for JaroWinkler<S> {
+    type Output = f64;
+
+    fn distance(&self) -> f64 {
+        JaroWinkler::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        JaroWinkler::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> f64 {
+        JaroWinkler::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        JaroWinkler::normalized_similarity(self)
+    }
+}
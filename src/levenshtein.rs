@@ -1,4 +1,7 @@
+use crate::bit_parallel::bit_parallel_distance;
+use crate::{Alignment, EditOperation, StringMetric};
 use std::cmp::max;
+use std::hash::Hash;
 
 /// Calculate the `Levenshtein` distance between two strings.
 /// The Levenshtein distance between two words is the minimum number of single-character edits
@@ -19,14 +22,25 @@ use std::cmp::max;
 ///
 /// ```
 ///
-pub struct Levenshtein {
+/// Both strings may be borrowed `&str` as well as owned `String`:
+///
+/// ```
+/// use text_distance::Levenshtein;
+///
+/// let levenshtein = Levenshtein {src: "karolin",  tar: "kathrin"};
+///
+/// assert_eq!(3, levenshtein.distance());
+///
+/// ```
+///
+pub struct Levenshtein<S = String> {
     /// Source string
-    pub src: String,
+    pub src: S,
     /// Target string
-    pub tar: String,
+    pub tar: S,
 }
 
-impl Levenshtein {
+impl<S: AsRef<str>> Levenshtein<S> {
     /// Calculate the `Levenshtein` distance between two strings.
     /// The distance is the number of edit operations needed to transform the source string into the target string.
     /// The edit operations are:
@@ -34,6 +48,8 @@ impl Levenshtein {
     /// - insertion (addition) of a single character at any position in the string.
     /// - substitution (replacement) of a single character with another character.
     ///
+    /// It is computed with Myers' bit-parallel algorithm, 64 characters of the shorter string at a time.
+    ///
     /// ### Examples
     ///
     /// ```
@@ -45,34 +61,10 @@ impl Levenshtein {
     ///
     /// ```
     pub fn distance(&self) -> usize {
-        let src_len = self.src.chars().count();
-        let tar_len = self.tar.chars().count();
-
-        // initialize the matrix
-        let mut matrix: Vec<Vec<usize>> = vec![vec![0; tar_len + 1]; src_len + 1];
-
-        for i in 1..(src_len + 1) {
-            matrix[i][0] = i;
-        }
-
-        for i in 1..(tar_len + 1) {
-            matrix[0][i] = i;
-        }
-
-        // apply edit operations
-        for (i, s_char) in self.src.chars().enumerate() {
-            for (j, t_char) in self.tar.chars().enumerate() {
-                let substitution_cost = if s_char == t_char { 0 } else { 1 };
-                let operations = [
-                    matrix[i][j + 1] + 1,             // deletion
-                    matrix[i + 1][j] + 1,             // insertion
-                    matrix[i][j] + substitution_cost, // substitution
-                ];
-                matrix[i + 1][j + 1] = *operations.iter().min().unwrap();
-            }
-        }
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
 
-        matrix[src_len][tar_len]
+        generic_levenshtein(&src, &tar)
     }
 
     /// Calculate the `normalized distance` between two strings.
@@ -94,8 +86,8 @@ impl Levenshtein {
     ///
     pub fn normalized_distance(&self) -> f64 {
         let maximum = max(
-            self.src.clone().chars().count(),
-            self.tar.clone().chars().count(),
+            self.src.as_ref().chars().count(),
+            self.tar.as_ref().chars().count(),
         );
         let str_distance = self.distance();
         if maximum != 0 {
@@ -124,8 +116,8 @@ impl Levenshtein {
     ///
     pub fn similarity(&self) -> usize {
         let maximum = max(
-            self.src.clone().chars().count(),
-            self.tar.clone().chars().count(),
+            self.src.as_ref().chars().count(),
+            self.tar.as_ref().chars().count(),
         );
         let str_distance = self.distance();
 
@@ -154,4 +146,341 @@ impl Levenshtein {
 
         1.0 - str_normalized_distance
     }
+
+    /// Find the edit operations which transform the source string into the target string.
+    /// The number of operations other than [`EditOperation::Keep`] is equal to the distance.
+    /// Long strings are aligned with Hirschberg's divide and conquer algorithm,
+    /// which only keeps rows as long as the shorter string in memory.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{EditOperation, Levenshtein};
+    ///
+    /// let levenshtein = Levenshtein {src: "cat".to_string(),  tar: "cut".to_string()};
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         EditOperation::Keep {src_pos: 0, tar_pos: 0},
+    ///         EditOperation::Substitute {src_pos: 1, tar_pos: 1},
+    ///         EditOperation::Keep {src_pos: 2, tar_pos: 2},
+    ///     ],
+    ///     levenshtein.edit_script()
+    /// );
+    ///
+    /// ```
+    pub fn edit_script(&self) -> Vec<EditOperation> {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        generic_levenshtein_edit_script(&src, &tar)
+    }
+
+    /// Find the edit operations and align the source and target strings to each other.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Levenshtein;
+    ///
+    /// let alignment = Levenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string()}.alignment();
+    ///
+    /// assert_eq!("karolin", alignment.src);
+    /// assert_eq!("kathrin", alignment.tar);
+    /// assert_eq!(3, alignment.distance());
+    ///
+    /// ```
+    pub fn alignment(&self) -> Alignment {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+        let operations = generic_levenshtein_edit_script(&src, &tar);
+
+        Alignment::new(&src, &tar, operations)
+    }
+
+    /// Calculate the `Levenshtein` distance if it is at most `max`.
+    /// If the distance is greater than `max`, then return `None`.
+    /// Only a band of `2 * max + 1` diagonals of the matrix is filled,
+    /// and the calculation stops as soon as every cell of a row exceeds `max`,
+    /// so it is much faster than [`Levenshtein::distance`] for small bounds.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Levenshtein;
+    ///
+    /// let levenshtein = Levenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string()};
+    ///
+    /// assert_eq!(Some(3), levenshtein.distance_within(3));
+    /// assert_eq!(None, levenshtein.distance_within(2));
+    ///
+    /// ```
+    pub fn distance_within(&self, max: usize) -> Option<usize> {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        generic_levenshtein_within(&src, &tar, max)
+    }
+}
+
+/// Calculate the `Levenshtein` distance between two sequences of arbitrary elements.
+/// It is the same algorithm as [`Levenshtein::distance`], but instead of characters
+/// it compares words, token ids, bytes or any other `Eq` items.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_levenshtein;
+///
+/// let src: Vec<&str> = "the cat sat on the mat".split_whitespace().collect();
+/// let tar: Vec<&str> = "the cat sat on a mat".split_whitespace().collect();
+///
+/// assert_eq!(1, generic_levenshtein(&src, &tar));
+/// assert_eq!(3, generic_levenshtein(b"karolin", b"kathrin"));
+///
+/// ```
+pub fn generic_levenshtein<T: Eq + Hash>(src: &[T], tar: &[T]) -> usize {
+    bit_parallel_distance(src, tar, false)
+}
+
+/// Calculate the `Levenshtein` distance between two sequences of arbitrary elements if it is at most `max`.
+/// It is the same algorithm as [`Levenshtein::distance_within`], applied to arbitrary elements.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_levenshtein_within;
+///
+/// assert_eq!(Some(1), generic_levenshtein_within(&[1, 2, 3], &[1, 3], 1));
+/// assert_eq!(None, generic_levenshtein_within(b"abcdef", b"ab", 3));
+///
+/// ```
+pub fn generic_levenshtein_within<T: Eq>(src: &[T], tar: &[T], max: usize) -> Option<usize> {
+    let (src_len, tar_len) = (src.len(), tar.len());
+    if src_len.abs_diff(tar_len) > max {
+        return None;
+    }
+
+    // every value above `max` is stored as `max + 1`
+    let exceeded = max + 1;
+    let mut previous: Vec<usize> = (0..=tar_len).map(|j| j.min(exceeded)).collect();
+    let mut current = vec![exceeded; tar_len + 1];
+
+    for i in 1..=src_len {
+        // only the cells at most `max` diagonals away can hold a value within the bound
+        let start = if i > max { i - max } else { 1 };
+        let end = (i + max).min(tar_len);
+
+        current[0] = i.min(exceeded);
+        current[start - 1] = if start == 1 { current[0] } else { exceeded };
+        let mut row_min = current[start - 1];
+
+        for j in start..=end {
+            let substitution_cost = if src[i - 1] == tar[j - 1] { 0 } else { 1 };
+            let operations = [
+                previous[j] + 1,                     // deletion
+                current[j - 1] + 1,                  // insertion
+                previous[j - 1] + substitution_cost, // substitution
+            ];
+            current[j] = (*operations.iter().min().unwrap()).min(exceeded);
+            row_min = row_min.min(current[j]);
+        }
+        if end < tar_len {
+            current[end + 1] = exceeded;
+        }
+
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[tar_len]).filter(|&distance| distance <= max)
+}
+
+/// Find the edit operations which transform `src` into `tar` with the `Levenshtein` distance.
+/// It is the same algorithm as [`Levenshtein::edit_script`], applied to arbitrary elements.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{generic_levenshtein_edit_script, EditOperation};
+///
+/// let src: Vec<&str> = "the cat sat".split_whitespace().collect();
+/// let tar: Vec<&str> = "the cat sat down".split_whitespace().collect();
+///
+/// assert_eq!(
+///     vec![
+///         EditOperation::Keep {src_pos: 0, tar_pos: 0},
+///         EditOperation::Keep {src_pos: 1, tar_pos: 1},
+///         EditOperation::Keep {src_pos: 2, tar_pos: 2},
+///         EditOperation::Insert {tar_pos: 3},
+///     ],
+///     generic_levenshtein_edit_script(&src, &tar)
+/// );
+///
+/// ```
+pub fn generic_levenshtein_edit_script<T: Eq>(src: &[T], tar: &[T]) -> Vec<EditOperation> {
+    if (src.len() + 1) * (tar.len() + 1) <= MAX_MATRIX_CELLS {
+        return matrix_edit_script(src, tar);
+    }
+
+    // Hirschberg's algorithm keeps rows as long as the target, so the shorter sequence is the target
+    let mut operations = Vec::with_capacity(max(src.len(), tar.len()));
+    if tar.len() <= src.len() {
+        hirschberg(src, tar, 0, 0, &mut operations);
+        operations
+    } else {
+        hirschberg(tar, src, 0, 0, &mut operations);
+        operations.into_iter().map(EditOperation::inverted).collect()
+    }
+}
+
+// Larger inputs are aligned with Hirschberg's algorithm, which needs memory linear
+// in the length of the inputs instead of a full matrix
+const MAX_MATRIX_CELLS: usize = 1 << 20;
+
+fn hirschberg<T: Eq>(
+    src: &[T],
+    tar: &[T],
+    src_offset: usize,
+    tar_offset: usize,
+    operations: &mut Vec<EditOperation>,
+) {
+    if src.len() < 2 || (src.len() + 1) * (tar.len() + 1) <= MAX_MATRIX_CELLS {
+        let script = matrix_edit_script(src, tar);
+        operations.extend(script.into_iter().map(|op| op.shifted(src_offset, tar_offset)));
+        return;
+    }
+
+    // the optimal alignment of the first half of the source ends in the column
+    // where the costs of both halves add up to the distance
+    let src_middle = src.len() / 2;
+    let tar_middle = {
+        let forward = last_row(&src[..src_middle], tar, false);
+        let backward = last_row(&src[src_middle..], tar, true);
+        (0..=tar.len())
+            .min_by_key(|&j| forward[j] + backward[tar.len() - j])
+            .unwrap()
+    };
+
+    hirschberg(&src[..src_middle], &tar[..tar_middle], src_offset, tar_offset, operations);
+    hirschberg(
+        &src[src_middle..],
+        &tar[tar_middle..],
+        src_offset + src_middle,
+        tar_offset + tar_middle,
+        operations,
+    );
+}
+
+// The last row of the matrix, i.e. the distances between `src` and every prefix of `tar`.
+// If `reversed` is true, then both sequences are read backwards, which gives the distances
+// between `src` and every suffix of `tar`.
+fn last_row<T: Eq>(src: &[T], tar: &[T], reversed: bool) -> Vec<usize> {
+    let position = |items: &[T], index: usize| -> usize {
+        if reversed {
+            items.len() - 1 - index
+        } else {
+            index
+        }
+    };
+
+    let mut row: Vec<usize> = (0..=tar.len()).collect();
+    for i in 0..src.len() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for j in 0..tar.len() {
+            let above = row[j + 1];
+            let substitution_cost = if src[position(src, i)] == tar[position(tar, j)] { 0 } else { 1 };
+            row[j + 1] = *[above + 1, row[j] + 1, diagonal + substitution_cost]
+                .iter()
+                .min()
+                .unwrap();
+            diagonal = above;
+        }
+    }
+
+    row
+}
+
+fn matrix_edit_script<T: Eq>(src: &[T], tar: &[T]) -> Vec<EditOperation> {
+    let matrix = levenshtein_matrix(src, tar);
+
+    // walk back from the bottom right corner, preferring diagonal moves
+    let mut operations = Vec::new();
+    let (mut i, mut j) = (src.len(), tar.len());
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let substitution_cost = if src[i - 1] == tar[j - 1] { 0 } else { 1 };
+            if matrix[i][j] == matrix[i - 1][j - 1] + substitution_cost {
+                operations.push(if substitution_cost == 0 {
+                    EditOperation::Keep { src_pos: i - 1, tar_pos: j - 1 }
+                } else {
+                    EditOperation::Substitute { src_pos: i - 1, tar_pos: j - 1 }
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && matrix[i][j] == matrix[i - 1][j] + 1 {
+            operations.push(EditOperation::Delete { src_pos: i - 1 });
+            i -= 1;
+        } else {
+            operations.push(EditOperation::Insert { tar_pos: j - 1 });
+            j -= 1;
+        }
+    }
+    operations.reverse();
+
+    operations
+}
+
+fn levenshtein_matrix<T: Eq>(src: &[T], tar: &[T]) -> Vec<Vec<usize>> {
+    // initialize the matrix
+    let mut matrix: Vec<Vec<usize>> = vec![vec![0; tar.len() + 1]; src.len() + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    // apply edit operations
+    for (i, s_item) in src.iter().enumerate() {
+        for (j, t_item) in tar.iter().enumerate() {
+            let substitution_cost = if s_item == t_item { 0 } else { 1 };
+            let operations = [
+                matrix[i][j + 1] + 1,             // deletion
+                matrix[i + 1][j] + 1,             // insertion
+                matrix[i][j] + substitution_cost, // substitution
+            ];
+            matrix[i + 1][j + 1] = *operations.iter().min().unwrap();
+        }
+    }
+
+    matrix
+}
+
+impl<S: AsRef<str>> StringMetric for Levenshtein<S> {
+    type Output = usize;
+
+    fn distance(&self) -> usize {
+        Levenshtein::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        Levenshtein::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> usize {
+        Levenshtein::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        Levenshtein::normalized_similarity(self)
+    }
 }
@@ -1,11 +1,51 @@
+pub use self::alignment::*;
+pub use self::bag_jaccard::*;
+pub use self::bit_hamming::*;
+pub use self::cosine::*;
+pub use self::cost::*;
 pub use self::damerau_levenshtein::*;
+pub use self::dice::*;
+pub use self::error::*;
 pub use self::hamming::*;
 pub use self::jaccard::*;
 pub use self::jaro_winkler::*;
 pub use self::levenshtein::*;
+pub use self::lsh::*;
+pub use self::metric::*;
+pub use self::min_hash::*;
+pub use self::overlap::*;
+pub use self::query::*;
+pub use self::strcmp95::*;
+pub use self::tokenizer::*;
+pub use self::tversky::*;
+pub use self::weighted_damerau_levenshtein::*;
+pub use self::weighted_jaccard::*;
+pub use self::weighted_levenshtein::*;
 
+mod alignment;
+mod alphabet;
+mod bag_jaccard;
+mod bit_hamming;
+mod bit_parallel;
+mod cosine;
+mod cost;
 mod damerau_levenshtein;
+mod dice;
+mod error;
 mod hamming;
+mod hash;
 mod jaccard;
 mod jaro_winkler;
+mod lsh;
 mod levenshtein;
+mod metric;
+mod min_hash;
+mod overlap;
+mod query;
+mod set_metric;
+mod strcmp95;
+mod tokenizer;
+mod tversky;
+mod weighted_damerau_levenshtein;
+mod weighted_jaccard;
+mod weighted_levenshtein;
@@ -0,0 +1,201 @@
+use crate::hash::fnv1a;
+use crate::jaccard::jaccard_of_sets;
+use crate::tokenizer::TOKENIZE_ERROR;
+use crate::{Error, MinHash, Tokenizer};
+use std::collections::{HashMap, HashSet};
+
+/// An index of documents for finding near duplicates with `Locality-Sensitive Hashing`.
+/// The [`MinHash`] signature of each document is split into `bands` bands of `rows` values,
+/// and documents with an identical band become candidate pairs.
+/// Two documents with `Jaccard` index `s` become candidates with probability `1 - (1 - s^rows)^bands`,
+/// which rises steeply around [`LshIndex::threshold`]: more rows make candidates rarer, more bands make them more likely.
+/// Candidates are then verified with the exact `Jaccard` index of their tokens.
+/// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Locality-sensitive_hashing)
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{LshIndex, SimilarPair};
+///
+/// let mut index = LshIndex::new(20, 5, 42, 3).unwrap();
+/// index.insert("Apple iPhone 13 Pro Max 256GB Graphite");
+/// index.insert("Samsung Galaxy S21 Ultra 128GB Black");
+/// index.insert("Apple iPhone 13 Pro Max 256 GB Graphite");
+///
+/// let pairs = index.similar_pairs(0.8);
+///
+/// assert_eq!(1, pairs.len());
+/// assert_eq!((0, 2), (pairs[0].first, pairs[0].second));
+/// assert!(pairs[0].similarity >= 0.8);
+///
+/// ```
+#[derive(Debug, Clone)]
+pub struct LshIndex<Q = usize> {
+    min_hash: MinHash<Q>,
+    bands: usize,
+    rows: usize,
+    // the tokens of each document, for verifying candidates
+    documents: Vec<HashSet<String>>,
+    // for each band, the documents in each bucket
+    buckets: Vec<HashMap<u64, Vec<usize>>>,
+}
+
+/// A pair of documents of an [`LshIndex`] together with the `Jaccard` index of their tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimilarPair {
+    /// Id of the earlier document
+    pub first: usize,
+    /// Id of the later document
+    pub second: usize,
+    /// Exact `Jaccard` index
+    pub similarity: f64,
+}
+
+impl<Q: Tokenizer> LshIndex<Q> {
+    /// Create an empty index with signatures of `bands * rows` hashes computed with `seed`,
+    /// and the tokens given by `qval` as in [`Jaccard`](crate::Jaccard).
+    /// If `bands` or `rows` is 0, then return [`Error::InvalidParameter`].
+    pub fn new(bands: usize, rows: usize, seed: u64, qval: Q) -> Result<Self, Error> {
+        for (name, value) in [("bands", bands), ("rows", rows)] {
+            if value == 0 {
+                return Err(Error::InvalidParameter {
+                    name,
+                    reason: "must be at least 1".to_string(),
+                });
+            }
+        }
+
+        Ok(LshIndex {
+            min_hash: MinHash {
+                num_hashes: bands * rows,
+                seed,
+                qval,
+            },
+            bands,
+            rows,
+            documents: Vec::new(),
+            buckets: vec![HashMap::new(); bands],
+        })
+    }
+
+    /// Add a document and return its id, which is the number of documents added before it.
+    /// If the document can't be tokenized, then panic.
+    pub fn insert(&mut self, text: &str) -> usize {
+        self.try_insert(text).expect(TOKENIZE_ERROR)
+    }
+
+    /// Add a document and return its id, which is the number of documents added before it.
+    /// If the document can't be tokenized, e.g. it is shorter than `qval`,
+    /// then return the [`Error`] of the tokenizer and don't add it.
+    pub fn try_insert(&mut self, text: &str) -> Result<usize, Error> {
+        let tokens = self.min_hash.qval.tokenize(text)?;
+        let signature = self.min_hash.try_generic_signature(&tokens)?;
+
+        let id = self.documents.len();
+        for (band, buckets) in signature.values.chunks(self.rows).zip(&mut self.buckets) {
+            buckets.entry(fnv1a(band)).or_default().push(id);
+        }
+        self.documents.push(tokens.into_iter().collect());
+
+        Ok(id)
+    }
+
+    /// Find the documents whose `Jaccard` index with `text` is at least `threshold`, without adding `text`.
+    /// The documents are sorted by id.
+    /// If `text` can't be tokenized, then return the [`Error`] of the tokenizer.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::LshIndex;
+    ///
+    /// let mut index = LshIndex::new(20, 5, 42, 0).unwrap();
+    /// index.insert("red cotton t-shirt size m");
+    /// index.insert("blue denim jeans size 32");
+    ///
+    /// assert_eq!(Ok(vec![(0, 1.0)]), index.query("size m red cotton t-shirt", 0.9));
+    /// assert_eq!(Ok(vec![]), index.query("green wool sweater", 0.5));
+    ///
+    /// ```
+    pub fn query(&self, text: &str, threshold: f64) -> Result<Vec<(usize, f64)>, Error> {
+        let tokens = self.min_hash.qval.tokenize(text)?;
+        let signature = self.min_hash.try_generic_signature(&tokens)?;
+        let tokens: HashSet<String> = tokens.into_iter().collect();
+
+        let mut candidates: Vec<usize> = signature
+            .values
+            .chunks(self.rows)
+            .zip(&self.buckets)
+            .filter_map(|(band, buckets)| buckets.get(&fnv1a(band)))
+            .flatten()
+            .copied()
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        Ok(candidates
+            .into_iter()
+            .map(|id| (id, 1.0 - jaccard_of_sets(&tokens, &self.documents[id])))
+            .filter(|&(_, similarity)| similarity >= threshold)
+            .collect())
+    }
+}
+
+impl<Q> LshIndex<Q> {
+    /// The number of documents in the index.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Returns `true` if no document was added.
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// The `Jaccard` index at which documents become candidates with a probability of about one half,
+    /// approximately `(1 / bands)^(1 / rows)`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::LshIndex;
+    ///
+    /// assert_eq!(0.5492802716530588, LshIndex::new(20, 5, 0, 1).unwrap().threshold());
+    ///
+    /// ```
+    pub fn threshold(&self) -> f64 {
+        (1.0 / self.bands as f64).powf(1.0 / self.rows as f64)
+    }
+
+    /// All pairs of documents which share a bucket in at least one band, sorted by ids.
+    /// They include pairs of any similarity, see [`LshIndex::similar_pairs`] for verified pairs.
+    pub fn candidate_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs: HashSet<(usize, usize)> = HashSet::new();
+        for bucket in self.buckets.iter().flat_map(|buckets| buckets.values()) {
+            for (i, &first) in bucket.iter().enumerate() {
+                for &second in &bucket[i + 1..] {
+                    pairs.insert((first, second));
+                }
+            }
+        }
+
+        let mut pairs: Vec<(usize, usize)> = pairs.into_iter().collect();
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// The candidate pairs whose exact `Jaccard` index is at least `threshold`, sorted by ids.
+    /// Pairs above the threshold are missed only if none of their bands are equal,
+    /// which is unlikely when `threshold` is well above [`LshIndex::threshold`].
+    pub fn similar_pairs(&self, threshold: f64) -> Vec<SimilarPair> {
+        self.candidate_pairs()
+            .into_iter()
+            .map(|(first, second)| SimilarPair {
+                first,
+                second,
+                similarity: 1.0 - jaccard_of_sets(&self.documents[first], &self.documents[second]),
+            })
+            .filter(|pair| pair.similarity >= threshold)
+            .collect()
+    }
+}
@@ -0,0 +1,143 @@
+use crate::Error;
+use std::fmt::Debug;
+
+/// A numeric value returned by [`StringMetric::distance`] and [`StringMetric::similarity`].
+///
+/// Edit distances such as `Levenshtein` count operations and return `usize`,
+/// while metrics such as `Jaccard` or `JaroWinkler` return `f64`.
+pub trait Score: Copy + PartialOrd + Debug {
+    /// Convert the score to `f64`.
+    fn to_f64(self) -> f64;
+}
+
+impl Score for usize {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Score for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+/// The common interface of every algorithm in this crate.
+/// It allows writing code that is generic over the algorithm used to compare two strings.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Hamming, Levenshtein, StringMetric};
+///
+/// fn is_close<M: StringMetric>(metric: &M) -> bool {
+///     metric.normalized_similarity() > 0.5
+/// }
+///
+/// assert!(is_close(&Levenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string()}));
+/// assert!(is_close(&Hamming {src: "karolin".to_string(),  tar: "kathrin".to_string()}));
+///
+/// ```
+pub trait StringMetric {
+    /// The type returned by [`distance`](StringMetric::distance) and [`similarity`](StringMetric::similarity).
+    type Output: Score;
+
+    /// Calculate the distance between two strings.
+    fn distance(&self) -> Self::Output;
+
+    /// Calculate the distance between two strings, scaled to be between 0.0 and 1.0.
+    fn normalized_distance(&self) -> f64;
+
+    /// Calculate the similarity between two strings.
+    fn similarity(&self) -> Self::Output;
+
+    /// Calculate the similarity between two strings, scaled to be between 0.0 and 1.0.
+    fn normalized_similarity(&self) -> f64;
+
+    /// Same as [`distance`](StringMetric::distance), but returns an [`Error`] instead of panicking on invalid input.
+    fn try_distance(&self) -> Result<Self::Output, Error> {
+        Ok(self.distance())
+    }
+
+    /// Same as [`normalized_distance`](StringMetric::normalized_distance), but returns an [`Error`] instead of panicking on invalid input.
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        Ok(self.normalized_distance())
+    }
+
+    /// Same as [`similarity`](StringMetric::similarity), but returns an [`Error`] instead of panicking on invalid input.
+    fn try_similarity(&self) -> Result<Self::Output, Error> {
+        Ok(self.similarity())
+    }
+
+    /// Same as [`normalized_similarity`](StringMetric::normalized_similarity), but returns an [`Error`] instead of panicking on invalid input.
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        Ok(self.normalized_similarity())
+    }
+
+    /// Box the metric as a [`DynStringMetric`], so that metrics with different
+    /// output types can be mixed and selected at runtime.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{DynStringMetric, Jaccard, Levenshtein, StringMetric};
+    ///
+    /// let metrics: Vec<Box<DynStringMetric>> = vec![
+    ///     Levenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string()}.boxed(),
+    ///     Jaccard {src: "karolin".to_string(),  tar: "kathrin".to_string(), qval: 1}.boxed(),
+    /// ];
+    ///
+    /// assert_eq!(3.0, metrics[0].distance());
+    /// assert_eq!(0.4444444444444444, metrics[1].distance());
+    ///
+    /// ```
+    fn boxed<'a>(self) -> Box<DynStringMetric<'a>>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(FloatMetric(self))
+    }
+}
+
+/// Object-safe form of [`StringMetric`] where every score is `f64`.
+/// Use [`StringMetric::boxed`] to create one.
+pub type DynStringMetric<'a> = dyn StringMetric<Output = f64> + 'a;
+
+/// Adapter which converts the scores of the wrapped metric to `f64`.
+pub struct FloatMetric<M>(pub M);
+
+impl<M: StringMetric> StringMetric for FloatMetric<M> {
+    type Output = f64;
+
+    fn distance(&self) -> f64 {
+        self.0.distance().to_f64()
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        self.0.normalized_distance()
+    }
+
+    fn similarity(&self) -> f64 {
+        self.0.similarity().to_f64()
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        self.0.normalized_similarity()
+    }
+
+    fn try_distance(&self) -> Result<f64, Error> {
+        self.0.try_distance().map(Score::to_f64)
+    }
+
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        self.0.try_normalized_distance()
+    }
+
+    fn try_similarity(&self) -> Result<f64, Error> {
+        self.0.try_similarity().map(Score::to_f64)
+    }
+
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        self.0.try_normalized_similarity()
+    }
+}
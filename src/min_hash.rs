@@ -0,0 +1,168 @@
+use crate::hash::{fnv1a, splitmix64};
+use crate::{Error, Tokenizer};
+use std::hash::Hash;
+
+const INVALID_INPUT: &str = "Can't create tokens from the text, or no hash functions were requested";
+const INCOMPATIBLE_SIGNATURES: &str = "Signatures must come from MinHash with the same seed and number of hashes";
+
+/// Estimate the `Jaccard` index of many strings with `MinHash` signatures.
+/// A signature keeps, for each of `num_hashes` hash functions, the smallest hash of the tokens of a string.
+/// Two strings have the same smallest hash with probability equal to the `Jaccard` index of their token sets,
+/// so the fraction of equal values in two signatures estimates the index with a standard error of
+/// `sqrt(J * (1 - J) / num_hashes)`, i.e. at most `0.5 / sqrt(num_hashes)`.
+///
+/// The hash functions are derived from `seed` with FNV-1a and SplitMix64, so signatures are the same
+/// on every run and can be stored. Only signatures computed with the same `seed` and `num_hashes` can be compared.
+/// [For more information see wikipedia article](https://en.wikipedia.org/wiki/MinHash)
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Jaccard, MinHash};
+///
+/// let min_hash = MinHash {num_hashes: 128, seed: 42, qval: 3};
+/// let src = min_hash.signature("the quick brown fox jumps over the lazy dog");
+/// let tar = min_hash.signature("the quick brown fox jumped over the lazy dog");
+///
+/// let exact = Jaccard {src: "the quick brown fox jumps over the lazy dog",  tar: "the quick brown fox jumped over the lazy dog", qval: 3}.similarity();
+///
+/// assert!((src.similarity(&tar) - exact).abs() < 0.1);
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinHash<Q = usize> {
+    /// Number of hash functions, i.e. the length of the signatures
+    pub num_hashes: usize,
+    /// Seed of the hash functions
+    pub seed: u64,
+    /// q-gram value, or any other [`Tokenizer`], as in [`Jaccard`](crate::Jaccard).
+    pub qval: Q,
+}
+
+impl<Q: Tokenizer> MinHash<Q> {
+    /// Compute the signature of a string.
+    /// If the string can't be tokenized or `num_hashes = 0`, then panic.
+    pub fn signature(&self, text: &str) -> MinHashSignature {
+        self.try_signature(text).expect(INVALID_INPUT)
+    }
+
+    /// Compute the signature of a string.
+    /// If the string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    /// If `num_hashes = 0`, then return [`Error::InvalidParameter`].
+    pub fn try_signature(&self, text: &str) -> Result<MinHashSignature, Error> {
+        let tokens = self.qval.tokenize(text)?;
+
+        self.try_generic_signature(&tokens)
+    }
+}
+
+impl<Q> MinHash<Q> {
+    /// Compute the signature of a collection of arbitrary tokens.
+    /// Tokens are hashed with their [`Hash`] implementation, so e.g. integers hash to different values
+    /// on platforms with a different byte order, while strings hash the same everywhere.
+    /// If `num_hashes = 0`, then panic.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::MinHash;
+    ///
+    /// let min_hash = MinHash {num_hashes: 64, seed: 7, qval: ()};
+    ///
+    /// assert_eq!(min_hash.generic_signature(&[1, 2, 3]), min_hash.generic_signature(&[3, 2, 1, 1]));
+    /// assert_eq!(0.0, min_hash.generic_signature(&[1, 2]).distance(&min_hash.generic_signature(&[2, 1])));
+    ///
+    /// ```
+    pub fn generic_signature<T: Hash>(&self, tokens: &[T]) -> MinHashSignature {
+        self.try_generic_signature(tokens).expect(INVALID_INPUT)
+    }
+
+    /// Compute the signature of a collection of arbitrary tokens.
+    /// If `num_hashes = 0`, then return [`Error::InvalidParameter`].
+    pub fn try_generic_signature<T: Hash>(&self, tokens: &[T]) -> Result<MinHashSignature, Error> {
+        if self.num_hashes == 0 {
+            return Err(Error::InvalidParameter {
+                name: "num_hashes",
+                reason: "signatures need at least one hash function".to_string(),
+            });
+        }
+
+        let seeds: Vec<u64> = (0..self.num_hashes as u64)
+            .map(|i| splitmix64(self.seed.wrapping_add(i)))
+            .collect();
+        let mut values = vec![u64::MAX; self.num_hashes];
+        for token in tokens {
+            let token_hash = fnv1a(token);
+            for (value, seed) in values.iter_mut().zip(&seeds) {
+                *value = (*value).min(splitmix64(token_hash ^ seed));
+            }
+        }
+
+        Ok(MinHashSignature {
+            seed: self.seed,
+            values,
+        })
+    }
+}
+
+/// The `MinHash` signature of a string, computed by [`MinHash`].
+/// The signature of a string without tokens has only `u64::MAX` values,
+/// so it is equal to the signature of any other string without tokens.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MinHashSignature {
+    /// Seed of the hash functions
+    pub seed: u64,
+    /// The smallest hash of the tokens for each hash function
+    pub values: Vec<u64>,
+}
+
+impl MinHashSignature {
+    /// Estimate the `Jaccard` distance between the strings of two signatures.
+    /// If the signatures have different seeds or lengths, then panic.
+    pub fn distance(&self, other: &MinHashSignature) -> f64 {
+        self.try_distance(other).expect(INCOMPATIBLE_SIGNATURES)
+    }
+
+    /// Estimate the `Jaccard` distance between the strings of two signatures.
+    /// If the signatures have different lengths, then return [`Error::LengthMismatch`].
+    /// If they have different seeds, then return [`Error::InvalidParameter`].
+    pub fn try_distance(&self, other: &MinHashSignature) -> Result<f64, Error> {
+        let similarity = self.try_similarity(other)?;
+
+        Ok(1.0 - similarity)
+    }
+
+    /// Estimate the `Jaccard` index between the strings of two signatures,
+    /// i.e. the fraction of hash functions with the same smallest hash.
+    /// If the signatures have different seeds or lengths, then panic.
+    pub fn similarity(&self, other: &MinHashSignature) -> f64 {
+        self.try_similarity(other).expect(INCOMPATIBLE_SIGNATURES)
+    }
+
+    /// Estimate the `Jaccard` index between the strings of two signatures.
+    /// If the signatures have different lengths, then return [`Error::LengthMismatch`].
+    /// If they have different seeds, then return [`Error::InvalidParameter`].
+    pub fn try_similarity(&self, other: &MinHashSignature) -> Result<f64, Error> {
+        if self.values.len() != other.values.len() {
+            return Err(Error::LengthMismatch {
+                src_len: self.values.len(),
+                tar_len: other.values.len(),
+            });
+        }
+        if self.seed != other.seed {
+            return Err(Error::InvalidParameter {
+                name: "seed",
+                reason: format!("signatures have different seeds, {} and {}", self.seed, other.seed),
+            });
+        }
+
+        let equal = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .filter(|(a, b)| a == b)
+            .count();
+
+        Ok(equal as f64 / self.values.len() as f64)
+    }
+}
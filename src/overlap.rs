@@ -0,0 +1,63 @@
+use crate::set_metric::{empty_set_distance, set_metric, set_sizes};
+use crate::tokenizer::TOKENIZE_ERROR;
+use std::hash::Hash;
+
+set_metric! {
+    /// Calculate the `Overlap` coefficient (`Szymkiewicz–Simpson` coefficient) between two sets of tokens.
+    /// The overlap coefficient is the size of the intersection divided by the size of the smaller set,
+    /// so it is 1.0 when one set is a subset of the other.
+    /// An empty set has coefficient 1.0 with another empty set and 0.0 with a non-empty one.
+    /// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Overlap_coefficient)
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Overlap;
+    ///
+    /// let overlap = Overlap {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0};
+    ///
+    /// assert_eq!(0.19999999999999996, overlap.distance());
+    /// assert_eq!(0.19999999999999996, overlap.normalized_distance());
+    /// assert_eq!(0.8, overlap.similarity());
+    /// assert_eq!(0.8, overlap.normalized_similarity());
+    ///
+    /// ```
+    ///
+    /// A subset has no distance:
+    ///
+    /// ```
+    /// use text_distance::Overlap;
+    ///
+    /// let overlap = Overlap {src: "night",  tar: "nightly", qval: 1};
+    ///
+    /// assert_eq!(0.0, overlap.distance());
+    ///
+    /// ```
+    ///
+    pub struct Overlap {}
+    metric: "Overlap",
+    coefficient: "overlap coefficient",
+    expect: TOKENIZE_ERROR,
+    errors: [],
+    distance: |_, src_tokens, tar_tokens| Ok(generic_overlap(&src_tokens, &tar_tokens)),
+}
+
+/// Calculate the `Overlap` distance between two collections of arbitrary tokens.
+/// Both collections are treated as sets, so repeated tokens are counted once.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_overlap;
+///
+/// assert_eq!(0.5, generic_overlap(&[1, 2, 3], &[3, 4]));
+///
+/// ```
+pub fn generic_overlap<T: Eq + Hash>(src: &[T], tar: &[T]) -> f64 {
+    let (src_len, tar_len, intersection_len) = set_sizes(src, tar);
+    if let Some(distance) = empty_set_distance(src_len, tar_len) {
+        return distance;
+    }
+
+    1.0 - intersection_len as f64 / src_len.min(tar_len) as f64
+}
@@ -0,0 +1,212 @@
+use crate::bit_parallel::PatternMasks;
+use crate::jaccard::jaccard_of_sets;
+use crate::jaro_winkler::{jaro_with_buffers, winkler_boost};
+use crate::{generic_damerau_levenshtein, try_generic_hamming, Error, JaroWinklerParams, Tokenizer};
+use std::cell::{RefCell, RefMut};
+use std::collections::HashSet;
+
+/// A source string prepared for comparison with many target strings.
+/// The characters of the source, the bit masks of the bit-parallel `Levenshtein`
+/// and `Damerau-Levenshtein` distances and the `Jaccard` tokens are computed only once,
+/// and the buffers of the `Jaro` similarity are reused between comparisons.
+///
+/// Every method returns the same value as `distance()` of the corresponding algorithm.
+/// A `Query` can't be shared between threads, clone it for each thread instead.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Levenshtein, Query};
+///
+/// let query = Query::new("kitten");
+/// let candidates = ["sitting", "mitten", "kitchen"];
+///
+/// let distances: Vec<usize> = candidates.iter().map(|candidate| query.levenshtein(candidate)).collect();
+///
+/// assert_eq!(vec![3, 1, 2], distances);
+/// assert_eq!(Levenshtein {src: "kitten",  tar: "sitting"}.distance(), query.levenshtein("sitting"));
+///
+/// ```
+#[derive(Debug, Clone)]
+pub struct Query {
+    text: String,
+    chars: Vec<char>,
+    masks: PatternMasks<char>,
+    // the `Jaccard` tokens of the source, for the last used `qval`
+    tokens: RefCell<Option<(usize, HashSet<String>)>>,
+    tar_chars: RefCell<Vec<char>>,
+    src_matches: RefCell<Vec<bool>>,
+    tar_matches: RefCell<Vec<bool>>,
+}
+
+impl Query {
+    /// Prepare the source string.
+    pub fn new(src: &str) -> Self {
+        let chars: Vec<char> = src.chars().collect();
+        let masks = PatternMasks::new(chars.iter().copied());
+
+        Query {
+            text: src.to_string(),
+            chars,
+            masks,
+            tokens: RefCell::new(None),
+            tar_chars: RefCell::new(Vec::new()),
+            src_matches: RefCell::new(Vec::new()),
+            tar_matches: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// The source string.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Calculate the `Levenshtein` distance between the source and `tar`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Query;
+    ///
+    /// assert_eq!(3, Query::new("karolin").levenshtein("kathrin"));
+    ///
+    /// ```
+    pub fn levenshtein(&self, tar: &str) -> usize {
+        self.masks.distance(tar.chars(), false)
+    }
+
+    /// Calculate the `Damerau-Levenshtein` distance between the source and `tar`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Query;
+    ///
+    /// let query = Query::new("ca");
+    ///
+    /// assert_eq!(3, query.damerau_levenshtein("abc", true));
+    /// assert_eq!(2, query.damerau_levenshtein("abc", false));
+    ///
+    /// ```
+    pub fn damerau_levenshtein(&self, tar: &str, restricted: bool) -> usize {
+        if restricted {
+            return self.masks.distance(tar.chars(), true);
+        }
+
+        let tar_chars = self.decode(tar);
+        generic_damerau_levenshtein(&self.chars, &tar_chars, false)
+    }
+
+    /// Calculate the `Hamming` distance between the source and `tar`.
+    /// If the strings have different lengths, then return [`Error::LengthMismatch`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Query;
+    ///
+    /// assert_eq!(Ok(3), Query::new("karolin").hamming("kathrin"));
+    ///
+    /// ```
+    pub fn hamming(&self, tar: &str) -> Result<usize, Error> {
+        let tar_chars = self.decode(tar);
+        try_generic_hamming(&self.chars, &tar_chars)
+    }
+
+    /// Calculate the `Jaccard` distance between the source and `tar`.
+    /// The tokens of the source are kept until the method is called with another `qval`.
+    /// If either string is shorter than `qval`, then return [`Error::InputTooShort`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Query;
+    ///
+    /// let query = Query::new("Rust is a programming language");
+    ///
+    /// assert_eq!(Ok(0.4285714285714286), query.jaccard("Python is a programming language too", 0));
+    ///
+    /// ```
+    pub fn jaccard(&self, tar: &str, qval: usize) -> Result<f64, Error> {
+        let mut tokens = self.tokens.borrow_mut();
+        if !matches!(*tokens, Some((cached_qval, _)) if cached_qval == qval) {
+            let src_tokens = qval.tokenize(&self.text)?.into_iter().collect();
+            *tokens = Some((qval, src_tokens));
+        }
+        let src_tokens = &tokens.as_ref().unwrap().1;
+
+        let tar_tokens: HashSet<String> = qval.tokenize(tar)?.into_iter().collect();
+        Ok(jaccard_of_sets(src_tokens, &tar_tokens))
+    }
+
+    /// Calculate the `Jaro` or `Jaro-Winkler` distance between the source and `tar`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Query;
+    ///
+    /// let query = Query::new("martha");
+    ///
+    /// assert_eq!(0.05555555555555547, query.jaro_winkler("marhta", false));
+    /// assert_eq!(0.03888888888888886, query.jaro_winkler("marhta", true));
+    ///
+    /// ```
+    pub fn jaro_winkler(&self, tar: &str, winklerize: bool) -> f64 {
+        let tar_chars = self.decode(tar);
+        let jaro = jaro_with_buffers(
+            &self.chars,
+            &tar_chars,
+            &mut self.src_matches.borrow_mut(),
+            &mut self.tar_matches.borrow_mut(),
+        );
+
+        if winklerize {
+            1.0 - winkler_boost(&self.chars, &tar_chars, jaro, &JaroWinklerParams::default())
+        } else {
+            1.0 - jaro
+        }
+    }
+
+    /// Calculate the `Jaro-Winkler` distance between the source and `tar` with custom parameters.
+    /// If the parameters are invalid, then return [`Error::InvalidParameter`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{JaroWinklerParams, Query};
+    ///
+    /// let query = Query::new("martha");
+    ///
+    /// assert_eq!(Ok(0.03888888888888886), query.jaro_winkler_with("marhta", &JaroWinklerParams::default()));
+    /// assert_eq!(Ok(0.05555555555555547), query.jaro_winkler_with("marhta", &JaroWinklerParams::new(0.0, 4, 0.7)));
+    ///
+    /// ```
+    pub fn jaro_winkler_with(&self, tar: &str, params: &JaroWinklerParams) -> Result<f64, Error> {
+        params.validate()?;
+        let tar_chars = self.decode(tar);
+        let jaro = jaro_with_buffers(
+            &self.chars,
+            &tar_chars,
+            &mut self.src_matches.borrow_mut(),
+            &mut self.tar_matches.borrow_mut(),
+        );
+
+        Ok(1.0 - winkler_boost(&self.chars, &tar_chars, jaro, params))
+    }
+
+    // Decode the target into the reused buffer.
+    fn decode(&self, tar: &str) -> RefMut<'_, Vec<char>> {
+        let mut tar_chars = self.tar_chars.borrow_mut();
+        tar_chars.clear();
+        tar_chars.extend(tar.chars());
+        tar_chars
+    }
+}
+
+impl From<&str> for Query {
+    fn from(src: &str) -> Self {
+        Query::new(src)
+    }
+}
+
@@ -0,0 +1,164 @@
+// Metrics comparing the sets of tokens of two strings, such as `Dice` or `Overlap`,
+// only differ in how they turn the sizes of the sets into a distance.
+// `set_metric!` defines their struct, methods and `StringMetric` impl from that distance.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// The number of distinct tokens in the source, in the target and in both of them.
+pub(crate) fn set_sizes<T: Eq + Hash>(src: &[T], tar: &[T]) -> (usize, usize, usize) {
+    let src_tokens: HashSet<&T> = src.iter().collect();
+    let tar_tokens: HashSet<&T> = tar.iter().collect();
+    let intersection_len = src_tokens.intersection(&tar_tokens).count();
+
+    (src_tokens.len(), tar_tokens.len(), intersection_len)
+}
+
+/// The distance when a set is empty: two empty sets are identical,
+/// and an empty set shares nothing with a non-empty one.
+pub(crate) fn empty_set_distance(src_len: usize, tar_len: usize) -> Option<f64> {
+    match (src_len, tar_len) {
+        (0, 0) => Some(0.0),
+        (0, _) | (_, 0) => Some(1.0),
+        _ => None,
+    }
+}
+
+/// Define a set metric struct with `src`, `tar` and `qval` fields followed by the given fields.
+/// `distance` computes the distance from the struct and the tokens of both strings,
+/// and `expect` is the panic message of the methods which don't return a `Result`.
+macro_rules! set_metric {
+    (
+        $(#[$struct_attr:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                pub $field:ident: $field_ty:ty,
+            )*
+        }
+        metric: $metric:literal,
+        coefficient: $coefficient:literal,
+        expect: $expect:expr,
+        errors: [$(#[$errors_attr:meta])*],
+        distance: |$metric_self:pat, $src_tokens:ident, $tar_tokens:ident| $distance:expr $(,)?
+    ) => {
+        $(#[$struct_attr])*
+        pub struct $name<S = String, Q = usize> {
+            /// Source string
+            pub src: S,
+            /// Target string
+            pub tar: S,
+            /// q-gram value, or any other [`Tokenizer`](crate::Tokenizer).
+            /// * If `qval = 0` then q-grams are words
+            /// * If `qval = 1` then q-grams are chars
+            /// * If `qval > 1` then q-grams are ngrams of `qval` chars
+            pub qval: Q,
+            $(
+                $(#[$field_attr])*
+                pub $field: $field_ty,
+            )*
+        }
+
+        impl<S: AsRef<str>, Q: $crate::Tokenizer> $name<S, Q> {
+            #[doc = concat!("Calculate the `", $metric, "` distance between two strings.")]
+            #[doc = concat!("The ", $metric, " distance is 1 minus the ", $coefficient, ".")]
+            #[doc = concat!("The ", $metric, " distance is always between 0.0 and 1.0.")]
+            /// When 0.0 then two strings have the same tokens.
+            /// When 1.0 then two strings have no token in common.
+            /// If [`try_distance`](Self::try_distance) returns an error, then panic.
+            pub fn distance(&self) -> f64 {
+                self.try_distance().expect($expect)
+            }
+
+            #[doc = concat!("Calculate the `", $metric, "` distance between two strings.")]
+            /// If a string can't be tokenized, e.g. it is shorter than `qval`,
+            /// then return the [`Error`](crate::Error) of the tokenizer.
+            $(#[$errors_attr])*
+            pub fn try_distance(&self) -> Result<f64, $crate::Error> {
+                let $src_tokens = self.qval.tokenize(self.src.as_ref())?;
+                let $tar_tokens = self.qval.tokenize(self.tar.as_ref())?;
+                let $metric_self = self;
+
+                $distance
+            }
+
+            /// Calculate the `normalized distance`.
+            /// The distance is already between 0.0 and 1.0, so it is equal to the distance.
+            pub fn normalized_distance(&self) -> f64 {
+                self.try_normalized_distance().expect($expect)
+            }
+
+            /// Calculate the `normalized distance`.
+            /// It returns the same errors as [`try_distance`](Self::try_distance).
+            pub fn try_normalized_distance(&self) -> Result<f64, $crate::Error> {
+                self.try_distance()
+            }
+
+            /// Calculate the `similarity`.
+            #[doc = concat!("The similarity is the ", $coefficient, ", i.e. 1 minus the distance.")]
+            pub fn similarity(&self) -> f64 {
+                self.try_similarity().expect($expect)
+            }
+
+            /// Calculate the `similarity`.
+            /// It returns the same errors as [`try_distance`](Self::try_distance).
+            pub fn try_similarity(&self) -> Result<f64, $crate::Error> {
+                let str_distance = self.try_distance()?;
+
+                Ok(1.0 - str_distance)
+            }
+
+            /// Calculate the `normalized similarity`.
+            /// The normalized similarity is 1 minus normalized distance.
+            pub fn normalized_similarity(&self) -> f64 {
+                self.try_normalized_similarity().expect($expect)
+            }
+
+            /// Calculate the `normalized similarity`.
+            /// It returns the same errors as [`try_distance`](Self::try_distance).
+            pub fn try_normalized_similarity(&self) -> Result<f64, $crate::Error> {
+                let str_normalized_distance = self.try_normalized_distance()?;
+
+                Ok(1.0 - str_normalized_distance)
+            }
+        }
+
+        impl<S: AsRef<str>, Q: $crate::Tokenizer> $crate::StringMetric for $name<S, Q> {
+            type Output = f64;
+
+            fn distance(&self) -> f64 {
+                $name::distance(self)
+            }
+
+            fn normalized_distance(&self) -> f64 {
+                $name::normalized_distance(self)
+            }
+
+            fn similarity(&self) -> f64 {
+                $name::similarity(self)
+            }
+
+            fn normalized_similarity(&self) -> f64 {
+                $name::normalized_similarity(self)
+            }
+
+            fn try_distance(&self) -> Result<f64, $crate::Error> {
+                $name::try_distance(self)
+            }
+
+            fn try_normalized_distance(&self) -> Result<f64, $crate::Error> {
+                $name::try_normalized_distance(self)
+            }
+
+            fn try_similarity(&self) -> Result<f64, $crate::Error> {
+                $name::try_similarity(self)
+            }
+
+            fn try_normalized_similarity(&self) -> Result<f64, $crate::Error> {
+                $name::try_normalized_similarity(self)
+            }
+        }
+    };
+}
+
+pub(crate) use set_metric;
@@ -0,0 +1,366 @@
+use crate::cost::single_char;
+use crate::jaro_winkler::{count_half_transpositions, find_matches};
+use crate::{Error, StringMetric};
+use std::borrow::Borrow;
+use std::cmp::min;
+use std::collections::HashMap;
+
+const INVALID_TABLE: &str = "Credits of similar characters must be between 0 and 1";
+
+// The pairs of similar characters of the original `strcmp95`, each worth 0.3 of a match.
+const CLASSIC_PAIRS: [(char, char); 39] = [
+    ('A', 'E'), ('A', 'I'), ('A', 'O'), ('A', 'U'), ('B', 'V'), ('E', 'I'), ('E', 'O'), ('E', 'U'),
+    ('I', 'O'), ('I', 'U'), ('O', 'U'), ('I', 'Y'), ('E', 'Y'), ('C', 'G'), ('E', 'F'), ('W', 'U'),
+    ('W', 'V'), ('X', 'K'), ('S', 'Z'), ('X', 'S'), ('Q', 'C'), ('U', 'V'), ('M', 'N'), ('L', 'I'),
+    ('Q', 'O'), ('P', 'R'), ('I', 'J'), ('2', 'Z'), ('5', 'S'), ('8', 'B'), ('1', 'I'), ('1', 'L'),
+    ('0', 'O'), ('0', 'Q'), ('C', 'K'), ('G', 'J'), ('E', ' '), ('Y', ' '), ('S', ' '),
+];
+const CLASSIC_CREDIT: f64 = 0.3;
+
+/// Pairs of similar characters, e.g. common OCR or phonetic confusions,
+/// with the partial credit that [`Strcmp95`] gives them as a fraction of a match.
+/// Pairs are symmetric and pairs which aren't in the table get no credit.
+///
+/// The default table is the one of the original `strcmp95`, where each pair is worth 0.3 of a match.
+/// It has only upper case letters, digits and the space.
+///
+/// The table can be loaded from text where each line is a pair of characters
+/// followed by the credit, e.g. `O 0 0.5`.
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::SimilarChars;
+///
+/// let classic = SimilarChars::default();
+/// let ocr = SimilarChars::parse("
+///     ## OCR confusions
+///     O 0 0.5
+///     l 1 0.5
+/// ").unwrap();
+///
+/// assert_eq!(0.3, classic.get('E', 'A'));
+/// assert_eq!(0.0, classic.get('e', 'a'));
+/// assert_eq!(0.5, ocr.get('1', 'l'));
+///
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarChars {
+    credits: HashMap<(char, char), f64>,
+}
+
+impl SimilarChars {
+    /// Create a table without similar characters.
+    pub fn empty() -> Self {
+        SimilarChars {
+            credits: HashMap::new(),
+        }
+    }
+
+    /// Set the credit of the pair `a` and `b`, in both directions.
+    pub fn insert(&mut self, a: char, b: char, credit: f64) {
+        self.credits.insert((a, b), credit);
+        self.credits.insert((b, a), credit);
+    }
+
+    /// Get the credit of the pair `a` and `b`, or 0.0 if they aren't similar.
+    pub fn get(&self, a: char, b: char) -> f64 {
+        *self.credits.get(&(a, b)).unwrap_or(&0.0)
+    }
+
+    /// Load a table from lines of `a b credit`.
+    /// If a line is malformed or a credit isn't between 0 and 1, then return [`Error::Parse`].
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut table = SimilarChars::empty();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let parse_error = |reason: &str| Error::Parse {
+                line: index + 1,
+                reason: reason.to_string(),
+            };
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 3 {
+                return Err(parse_error("expected `a b credit`"));
+            }
+            let a = single_char(fields[0]).ok_or_else(|| parse_error("expected a single character"))?;
+            let b = single_char(fields[1]).ok_or_else(|| parse_error("expected a single character"))?;
+            let credit: f64 = fields[2]
+                .parse()
+                .map_err(|_| parse_error("expected a number"))?;
+            if !is_valid_credit(credit) {
+                return Err(parse_error("credit must be between 0 and 1"));
+            }
+
+            table.insert(a, b, credit);
+        }
+
+        Ok(table)
+    }
+
+    /// Check that every credit is between 0.0 and 1.0.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        match self.credits.iter().find(|(_, &credit)| !is_valid_credit(credit)) {
+            Some(((a, b), credit)) => Err(Error::InvalidParameter {
+                name: "similar",
+                reason: format!("credit of {:?} and {:?} must be between 0 and 1, got {:?}", a, b, credit),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for SimilarChars {
+    fn default() -> Self {
+        let mut table = SimilarChars::empty();
+        for (a, b) in CLASSIC_PAIRS {
+            table.insert(a, b, CLASSIC_CREDIT);
+        }
+        table
+    }
+}
+
+fn is_valid_credit(credit: f64) -> bool {
+    (0.0..=1.0).contains(&credit)
+}
+
+/// Calculate the `strcmp95` similarity between two strings, the `Jaro-Winkler` variant
+/// of the US Census Bureau used for record linkage.
+/// It extends the [`JaroWinkler`](crate::JaroWinkler) similarity in two ways:
+/// * Characters which don't match, but are in the table of [`SimilarChars`],
+///   count as a fraction of a match.
+/// * If `long_strings = true`, then the similarity of long strings which agree in more than half
+///   of their characters is increased further. It is not appropriate for fixed length fields
+///   such as phone numbers.
+///
+/// The common prefix stops at the first digit, and the long string adjustment doesn't apply
+/// to strings starting with a digit.
+/// The original `strcmp95` also ignores leading and trailing spaces and converts strings to upper case,
+/// which must be done before comparing to get the same scores.
+/// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance)
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{JaroWinkler, SimilarChars, Strcmp95};
+///
+/// let strcmp95 = Strcmp95 {src: "DWAYNE",  tar: "DUANE", similar: SimilarChars::default(), long_strings: true};
+///
+/// assert_eq!(0.10390909090909095, strcmp95.distance());
+/// assert_eq!(0.10390909090909095, strcmp95.normalized_distance());
+/// assert_eq!(0.896090909090909, strcmp95.similarity());
+/// assert_eq!(0.896090909090909, strcmp95.normalized_similarity());
+/// assert_eq!(0.8400000000000001, JaroWinkler {src: "DWAYNE",  tar: "DUANE", winklerize: true}.similarity());
+///
+/// ```
+///
+/// The table can be borrowed to compare many strings:
+///
+/// ```
+/// use text_distance::{SimilarChars, Strcmp95};
+///
+/// let similar = SimilarChars::default();
+/// let names = ["SHACKLEFORD", "SHACKELFORD", "DUNNINGHAM", "CUNNIGHAM"];
+///
+/// let scores: Vec<f64> = names
+///     .chunks(2)
+///     .map(|pair| Strcmp95 {src: pair[0],  tar: pair[1], similar: &similar, long_strings: true}.similarity())
+///     .collect();
+///
+/// assert_eq!(vec![0.9886363636363636, 0.9308641975308642], scores);
+///
+/// ```
+///
+pub struct Strcmp95<S = String, M = SimilarChars> {
+    /// Source string
+    pub src: S,
+    /// Target string
+    pub tar: S,
+    /// Table of similar characters, owned or borrowed
+    pub similar: M,
+    /// * If `long_strings = true` it adjusts the similarity of long strings.
+    /// * If `long_strings = false` it doesn't.
+    pub long_strings: bool,
+}
+
+impl<S: AsRef<str>, M: Borrow<SimilarChars>> Strcmp95<S, M> {
+    /// Calculate the `strcmp95` distance between two strings.
+    /// The distance is 1 minus the similarity.
+    /// If a credit of the table isn't between 0 and 1, then panic.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{SimilarChars, Strcmp95};
+    ///
+    /// let strcmp95 = Strcmp95 {src: "DIXON",  tar: "DICKSONX", similar: SimilarChars::default(), long_strings: false};
+    ///
+    /// assert_eq!(0.16066666666666662, strcmp95.distance());
+    ///
+    /// ```
+    pub fn distance(&self) -> f64 {
+        self.try_distance().expect(INVALID_TABLE)
+    }
+
+    /// Calculate the `strcmp95` distance between two strings.
+    /// If a credit of the table isn't between 0 and 1, then return [`Error::InvalidParameter`].
+    pub fn try_distance(&self) -> Result<f64, Error> {
+        let similar = self.similar.borrow();
+        similar.validate()?;
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        Ok(1.0 - strcmp95(&src, &tar, similar, self.long_strings))
+    }
+
+    /// Calculate the `normalized distance`.
+    /// The distance is already between 0.0 and 1.0, so it is equal to the distance.
+    pub fn normalized_distance(&self) -> f64 {
+        self.try_normalized_distance().expect(INVALID_TABLE)
+    }
+
+    /// Calculate the `normalized distance`.
+    /// If a credit of the table isn't between 0 and 1, then return [`Error::InvalidParameter`].
+    pub fn try_normalized_distance(&self) -> Result<f64, Error> {
+        self.try_distance()
+    }
+
+    /// Calculate the `similarity`.
+    /// The similarity is always between 0.0 and 1.0.
+    /// When 0.0 then two strings are completely different.
+    /// When 1.0 then two strings are equal.
+    pub fn similarity(&self) -> f64 {
+        self.try_similarity().expect(INVALID_TABLE)
+    }
+
+    /// Calculate the `similarity`.
+    /// If a credit of the table isn't between 0 and 1, then return [`Error::InvalidParameter`].
+    pub fn try_similarity(&self) -> Result<f64, Error> {
+        let str_distance = self.try_distance()?;
+
+        Ok(1.0 - str_distance)
+    }
+
+    /// Calculate the `normalized similarity`.
+    /// The normalized similarity is 1 minus normalized distance.
+    pub fn normalized_similarity(&self) -> f64 {
+        self.try_normalized_similarity().expect(INVALID_TABLE)
+    }
+
+    /// Calculate the `normalized similarity`.
+    /// If a credit of the table isn't between 0 and 1, then return [`Error::InvalidParameter`].
+    pub fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        let str_normalized_distance = self.try_normalized_distance()?;
+
+        Ok(1.0 - str_normalized_distance)
+    }
+}
+
+fn strcmp95(src: &[char], tar: &[char], similar: &SimilarChars, long_strings: bool) -> f64 {
+    let src_len = src.len();
+    let tar_len = tar.len();
+
+    if src_len == 0 && tar_len == 0 {
+        return 1.0;
+    } else if src_len == 0 || tar_len == 0 {
+        return 0.0;
+    } else if src == tar {
+        return 1.0;
+    }
+
+    let mut src_matches = Vec::new();
+    let mut tar_matches = Vec::new();
+    let common_chars = find_matches(src, tar, &mut src_matches, &mut tar_matches);
+    if common_chars == 0 {
+        return 0.0;
+    }
+    let transpositions = count_half_transpositions(src, tar, &src_matches, &tar_matches) / 2;
+
+    // Each unmatched source character gets the credit of the first similar unmatched target character,
+    // which can't be used again.
+    let min_len = min(src_len, tar_len);
+    let mut similar_credit = 0.0;
+    if min_len > common_chars {
+        let unmatched = src.iter().zip(&src_matches).filter(|(_, &matched)| !matched);
+        for (s_item, _) in unmatched {
+            for (t_item, t_used) in tar.iter().zip(tar_matches.iter_mut()) {
+                let credit = similar.get(*s_item, *t_item);
+                if !*t_used && credit > 0.0 {
+                    similar_credit += credit;
+                    *t_used = true;
+                    break;
+                }
+            }
+        }
+    }
+    let similar_chars = similar_credit + common_chars as f64;
+
+    let mut weight = ((similar_chars / src_len as f64)
+        + (similar_chars / tar_len as f64)
+        + ((common_chars - transpositions) as f64 / common_chars as f64))
+        / 3.0;
+
+    if weight > 0.7 {
+        let prefix_len = src
+            .iter()
+            .zip(tar)
+            .take(4)
+            .take_while(|(s_item, t_item)| s_item == t_item && !s_item.is_ascii_digit())
+            .count();
+        weight += prefix_len as f64 * 0.1 * (1.0 - weight);
+
+        if long_strings
+            && min_len > 4
+            && common_chars > prefix_len + 1
+            && 2 * common_chars >= min_len + prefix_len
+            && !src[0].is_ascii_digit()
+        {
+            let agreeing = (common_chars - prefix_len - 1) as f64;
+            weight += (1.0 - weight) * (agreeing / (src_len + tar_len - prefix_len * 2 + 2) as f64);
+        }
+    }
+
+    weight
+}
+
+impl<S: AsRef<str>, M: Borrow<SimilarChars>> StringMetric for Strcmp95<S, M> {
+    type Output = f64;
+
+    fn distance(&self) -> f64 {
+        Strcmp95::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        Strcmp95::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> f64 {
+        Strcmp95::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        Strcmp95::normalized_similarity(self)
+    }
+
+    fn try_distance(&self) -> Result<f64, Error> {
+        Strcmp95::try_distance(self)
+    }
+
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        Strcmp95::try_normalized_distance(self)
+    }
+
+    fn try_similarity(&self) -> Result<f64, Error> {
+        Strcmp95::try_similarity(self)
+    }
+
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        Strcmp95::try_normalized_similarity(self)
+    }
+}
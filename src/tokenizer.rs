@@ -0,0 +1,297 @@
+use crate::Error;
+use std::iter;
+
+/// The panic message of metrics whose only error comes from their tokenizer.
+pub(crate) const TOKENIZE_ERROR: &str = "Can't create tokens from the text";
+
+/// Splits a text into the tokens compared by token-based metrics such as [`Jaccard`](crate::Jaccard).
+///
+/// It is implemented for `usize`, where the value selects words (`0`), chars (`1`)
+/// or char n-grams (`n > 1`), and for the tokenizers of this module:
+/// [`Whitespace`], [`Chars`], [`CharNgrams`], [`PaddedCharNgrams`], [`WordNgrams`], [`Words`] and [`TokenizerFn`].
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{CharNgrams, Tokenizer, Whitespace};
+///
+/// assert_eq!(Ok(vec!["new".to_string(), "york".to_string()]), Whitespace.tokenize("new york"));
+/// assert_eq!(Ok(vec!["ne".to_string(), "ew".to_string()]), CharNgrams {n: 2}.tokenize("new"));
+/// assert_eq!(CharNgrams {n: 2}.tokenize("new"), 2.tokenize("new"));
+///
+/// ```
+pub trait Tokenizer {
+    /// Split the text into tokens.
+    /// If the text can't be tokenized, e.g. it is too short for an n-gram, then return an [`Error`].
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error>;
+}
+
+impl<T: Tokenizer + ?Sized> Tokenizer for &T {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        (**self).tokenize(text)
+    }
+}
+
+impl Tokenizer for usize {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        match *self {
+            // by words
+            0 => Whitespace.tokenize(text),
+            // by chars
+            1 => Chars.tokenize(text),
+            // by ngrams
+            qval => char_ngrams(text, qval),
+        }
+    }
+}
+
+/// Tokens separated by whitespace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Whitespace;
+
+impl Tokenizer for Whitespace {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        Ok(text.split_whitespace().map(String::from).collect())
+    }
+}
+
+/// Every char (Unicode scalar value) is a token.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Chars;
+
+impl Tokenizer for Chars {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        Ok(text.chars().map(String::from).collect())
+    }
+}
+
+/// Overlapping n-grams of `n` chars, see [`char_ngrams`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharNgrams {
+    /// Number of chars in an n-gram
+    pub n: usize,
+}
+
+impl Tokenizer for CharNgrams {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        char_ngrams(text, self.n)
+    }
+}
+
+/// Overlapping n-grams of `n` chars of the text padded with `n - 1` `start` markers
+/// before it and `n - 1` `end` markers after it, e.g. `"##ab##"` for `n = 3`.
+/// Every char is then part of `n` n-grams, so matching prefixes and suffixes count as much
+/// as matches in the middle, and texts shorter than `n` still have n-grams.
+/// The markers should be chars which don't occur in the texts.
+/// If `n = 0`, then return [`Error::InvalidParameter`].
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Jaccard, PaddedCharNgrams, Tokenizer};
+///
+/// let tokens = PaddedCharNgrams::new(3).tokenize("ab");
+///
+/// assert_eq!(Ok(vec!["##a".to_string(), "#ab".to_string(), "ab#".to_string(), "b##".to_string()]), tokens);
+/// assert_eq!(0.6, Jaccard {src: "ab",  tar: "abc", qval: PaddedCharNgrams {n: 2, start: '^', end: '$'}}.distance());
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaddedCharNgrams {
+    /// Number of chars in an n-gram
+    pub n: usize,
+    /// Marker of the start of the text
+    pub start: char,
+    /// Marker of the end of the text
+    pub end: char,
+}
+
+impl PaddedCharNgrams {
+    /// Create n-grams of `n` chars padded with `'#'` on both sides.
+    pub fn new(n: usize) -> Self {
+        PaddedCharNgrams { n, start: '#', end: '#' }
+    }
+}
+
+impl Tokenizer for PaddedCharNgrams {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        let padding = self.n.saturating_sub(1);
+        let chars: Vec<char> = iter::repeat_n(self.start, padding)
+            .chain(text.chars())
+            .chain(iter::repeat_n(self.end, padding))
+            .collect();
+        // unigrams aren't padded, so the empty text has none
+        if self.n == 1 && chars.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        ngrams(&chars, self.n, |ngram| ngram.iter().collect())
+    }
+}
+
+/// Overlapping n-grams of `n` extended grapheme clusters, see [`grapheme_ngrams`].
+///
+/// Requires the `unicode-segmentation` feature.
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphemeNgrams {
+    /// Number of grapheme clusters in an n-gram
+    pub n: usize,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl Tokenizer for GraphemeNgrams {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        grapheme_ngrams(text, self.n)
+    }
+}
+
+/// Overlapping n-grams of `n` words separated by whitespace, joined with a single space.
+/// If the text has fewer than `n` words, then return [`Error::InputTooShort`].
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Tokenizer, WordNgrams};
+///
+/// let bigrams = WordNgrams {n: 2}.tokenize("new  york city");
+///
+/// assert_eq!(Ok(vec!["new york".to_string(), "york city".to_string()]), bigrams);
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordNgrams {
+    /// Number of words in an n-gram
+    pub n: usize,
+}
+
+impl Tokenizer for WordNgrams {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        ngrams(&words, self.n, |ngram| ngram.join(" "))
+    }
+}
+
+/// Words made of alphanumeric chars, so that whitespace and punctuation both separate tokens
+/// and are dropped. The chars in `keep` are treated as part of words,
+/// e.g. `"#"` keeps hashtags and `"_"` keeps code identifiers whole.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Tokenizer, Words};
+///
+/// let text = "Hello, world! #rust_lang";
+///
+/// assert_eq!(Ok(vec!["Hello".to_string(), "world".to_string(), "rust".to_string(), "lang".to_string()]), Words::default().tokenize(text));
+/// assert_eq!(Ok(vec!["Hello".to_string(), "world".to_string(), "#rust_lang".to_string()]), Words {keep: "#_"}.tokenize(text));
+///
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Words<'a> {
+    /// Non-alphanumeric chars which are part of words
+    pub keep: &'a str,
+}
+
+impl Tokenizer for Words<'_> {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        let tokens = text
+            .split(|c: char| !(c.is_alphanumeric() || self.keep.contains(c)))
+            .filter(|word| !word.is_empty())
+            .map(String::from)
+            .collect();
+
+        Ok(tokens)
+    }
+}
+
+/// Tokens computed by a function, for formats the built-in tokenizers don't cover.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Jaccard, Tokenizer, TokenizerFn};
+///
+/// // SKUs such as "AB-1234-XL", compared by their dash-separated parts
+/// let sku = TokenizerFn {function: |text: &str| text.split('-').map(String::from).collect()};
+///
+/// assert_eq!(Ok(vec!["AB".to_string(), "1234".to_string(), "XL".to_string()]), sku.tokenize("AB-1234-XL"));
+/// assert_eq!(0.5, Jaccard {src: "AB-1234-XL",  tar: "AB-1234-M", qval: sku}.distance());
+///
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TokenizerFn<F> {
+    /// Function splitting a text into tokens
+    pub function: F,
+}
+
+impl<F: Fn(&str) -> Vec<String>> Tokenizer for TokenizerFn<F> {
+    fn tokenize(&self, text: &str) -> Result<Vec<String>, Error> {
+        Ok((self.function)(text))
+    }
+}
+
+/// Split the text into overlapping n-grams of `n` chars (Unicode scalar values).
+/// If the text is shorter than `n`, then return [`Error::InputTooShort`].
+/// If `n = 0`, then return [`Error::InvalidParameter`].
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{char_ngrams, generic_jaccard};
+///
+/// let src = char_ngrams("სახლი", 2).unwrap();
+/// let tar = char_ngrams("სახელი", 2).unwrap();
+///
+/// assert_eq!(vec!["სა", "ახ", "ხლ", "ლი"], src);
+/// assert_eq!(0.5, generic_jaccard(&src, &tar));
+///
+/// ```
+pub fn char_ngrams(text: &str, n: usize) -> Result<Vec<String>, Error> {
+    let chars: Vec<char> = text.chars().collect();
+    ngrams(&chars, n, |ngram| ngram.iter().collect())
+}
+
+/// Split the text into overlapping n-grams of `n` extended grapheme clusters,
+/// so that a letter followed by combining marks, or an emoji sequence, counts as one character.
+/// If the text is shorter than `n`, then return [`Error::InputTooShort`].
+/// If `n = 0`, then return [`Error::InvalidParameter`].
+///
+/// Requires the `unicode-segmentation` feature.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{char_ngrams, grapheme_ngrams};
+///
+/// // "é" written as "e" followed by a combining acute accent
+/// let text = "cafe\u{301}";
+///
+/// assert_eq!(vec!["ca", "af", "fe\u{301}"], grapheme_ngrams(text, 2).unwrap());
+/// assert_eq!(vec!["ca", "af", "fe", "e\u{301}"], char_ngrams(text, 2).unwrap());
+///
+/// ```
+#[cfg(feature = "unicode-segmentation")]
+pub fn grapheme_ngrams(text: &str, n: usize) -> Result<Vec<String>, Error> {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    ngrams(&graphemes, n, |ngram| ngram.concat())
+}
+
+fn ngrams<T>(units: &[T], n: usize, join: impl Fn(&[T]) -> String) -> Result<Vec<String>, Error> {
+    if n == 0 {
+        return Err(Error::InvalidParameter {
+            name: "n",
+            reason: "n-grams must have at least one element".to_string(),
+        });
+    }
+    if units.len() < n {
+        return Err(Error::InputTooShort {
+            len: units.len(),
+            min_len: n,
+        });
+    }
+
+    Ok(units.windows(n).map(join).collect())
+}
@@ -0,0 +1,108 @@
+use crate::cost::is_valid_cost;
+use crate::set_metric::{empty_set_distance, set_metric, set_sizes};
+use crate::Error;
+use std::hash::Hash;
+
+const INVALID_INPUT: &str = "Can't create tokens from the text, or alpha or beta is negative";
+
+set_metric! {
+    /// Calculate the `Tversky` index between two sets of tokens.
+    /// The Tversky index is the size of the intersection divided by the size of the intersection
+    /// plus `alpha` times the number of tokens only in the source and `beta` times the number of tokens only in the target.
+    /// Different `alpha` and `beta` make it asymmetric, e.g. with `alpha = 1` and `beta = 0`
+    /// it measures how much of the source is contained in the target.
+    /// * If `alpha = beta = 1` then it is the `Jaccard` index
+    /// * If `alpha = beta = 0.5` then it is the `Dice` coefficient
+    ///
+    /// Sets without common tokens have index 0, even with `alpha = beta = 0`.
+    /// Two empty sets have index 1.
+    ///
+    /// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Tversky_index)
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::Tversky;
+    ///
+    /// let tversky = Tversky {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0, alpha: 0.0, beta: 1.0};
+    ///
+    /// assert_eq!(0.19999999999999996, tversky.distance());
+    /// assert_eq!(0.19999999999999996, tversky.normalized_distance());
+    /// assert_eq!(0.8, tversky.similarity());
+    /// assert_eq!(0.8, tversky.normalized_similarity());
+    ///
+    /// ```
+    ///
+    /// Asymmetric weights, measuring how much of the source is in the target:
+    ///
+    /// ```
+    /// use text_distance::Tversky;
+    ///
+    /// let tversky = Tversky {src: "night",  tar: "nightly", qval: 1, alpha: 1.0, beta: 0.0};
+    /// let reversed = Tversky {src: "nightly",  tar: "night", qval: 1, alpha: 1.0, beta: 0.0};
+    ///
+    /// assert_eq!(0.0, tversky.distance());
+    /// assert_eq!(0.2857142857142857, reversed.distance());
+    ///
+    /// ```
+    ///
+    pub struct Tversky {
+        /// Weight of the tokens only in the source, must not be negative
+        pub alpha: f64,
+        /// Weight of the tokens only in the target, must not be negative
+        pub beta: f64,
+    }
+    metric: "Tversky",
+    coefficient: "Tversky index",
+    expect: INVALID_INPUT,
+    errors: [
+        /// If `alpha` or `beta` is negative, then return [`Error::InvalidParameter`].
+    ],
+    distance: |tversky, src_tokens, tar_tokens| try_generic_tversky(&src_tokens, &tar_tokens, tversky.alpha, tversky.beta),
+}
+
+/// Calculate the `Tversky` distance between two collections of arbitrary tokens.
+/// Both collections are treated as sets, so repeated tokens are counted once.
+/// If `alpha` or `beta` is negative, then panic.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_tversky;
+///
+/// assert_eq!(0.75, generic_tversky(&[1, 2, 3], &[3, 4], 1.0, 1.0));
+/// assert_eq!(0.6, generic_tversky(&[1, 2, 3], &[3, 4], 0.5, 0.5));
+///
+/// ```
+pub fn generic_tversky<T: Eq + Hash>(src: &[T], tar: &[T], alpha: f64, beta: f64) -> f64 {
+    try_generic_tversky(src, tar, alpha, beta).expect(INVALID_INPUT)
+}
+
+/// Calculate the `Tversky` distance between two collections of arbitrary tokens.
+/// If `alpha` or `beta` is negative, then return [`Error::InvalidParameter`].
+pub fn try_generic_tversky<T: Eq + Hash>(src: &[T], tar: &[T], alpha: f64, beta: f64) -> Result<f64, Error> {
+    for (name, weight) in [("alpha", alpha), ("beta", beta)] {
+        if !is_valid_cost(weight) {
+            return Err(Error::InvalidParameter {
+                name,
+                reason: format!("must not be negative, got {:?}", weight),
+            });
+        }
+    }
+
+    let (src_len, tar_len, intersection_len) = set_sizes(src, tar);
+    if let Some(distance) = empty_set_distance(src_len, tar_len) {
+        return Ok(distance);
+    }
+    let intersection = intersection_len as f64;
+    let src_only = (src_len - intersection_len) as f64;
+    let tar_only = (tar_len - intersection_len) as f64;
+
+    let denominator = intersection + alpha * src_only + beta * tar_only;
+    // only disjoint sets with `alpha = beta = 0` get here
+    if denominator == 0.0 {
+        return Ok(1.0);
+    }
+
+    Ok(1.0 - intersection / denominator)
+}
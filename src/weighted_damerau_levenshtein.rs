@@ -0,0 +1,548 @@
+use crate::alphabet::Alphabet;
+use crate::cost::{max_cost, min_cost, saturating_sub, validate_costs};
+use crate::weighted_levenshtein::levenshtein_max_distance;
+use crate::{Cost, Error, LevenshteinWeights, Score, StringMetric, SubstitutionCost};
+use std::hash::Hash;
+
+const INVALID_WEIGHTS: &str = "Edit operation weights must not be negative, and transpositions must cost at least half of an insertion and a deletion";
+
+/// Costs of the edit operations used by [`WeightedDamerauLevenshtein`].
+/// It is implemented for [`LevenshteinWeights`], where transposing two adjacent elements `ab` into `ba`
+/// costs as much as the larger of an insertion and a deletion, and for [`DamerauLevenshteinWeights`],
+/// where transpositions have a cost of their own.
+/// The unrestricted distance requires every transposition to cost at least half
+/// as much as an insertion and a deletion together.
+pub trait DamerauLevenshteinCosts<T = char> {
+    /// The type of the costs.
+    type Cost: Cost;
+
+    /// The cost of inserting an element.
+    fn insertion(&self) -> Self::Cost;
+
+    /// The cost of deleting an element.
+    fn deletion(&self) -> Self::Cost;
+
+    /// The cost of replacing `a` with `b`. It is only called for different elements.
+    fn substitution(&self, a: &T, b: &T) -> Self::Cost;
+
+    /// The cost of swapping the adjacent elements `first` and `second`. It is only called for different elements.
+    fn transposition(&self, first: &T, second: &T) -> Self::Cost;
+
+    /// Check that the costs are valid for the restricted or the unrestricted distance.
+    fn validate(&self, restricted: bool) -> Result<(), Error>;
+
+    /// The largest possible distance between sequences of the given lengths.
+    /// It is used to normalize distances.
+    fn max_distance(&self, src_len: usize, tar_len: usize) -> Self::Cost;
+}
+
+impl<T, C: Cost, Sub: SubstitutionCost<T, Cost = C>> DamerauLevenshteinCosts<T> for LevenshteinWeights<C, Sub> {
+    type Cost = C;
+
+    fn insertion(&self) -> C {
+        self.insertion
+    }
+
+    fn deletion(&self) -> C {
+        self.deletion
+    }
+
+    fn substitution(&self, a: &T, b: &T) -> C {
+        self.substitution.substitution(a, b)
+    }
+
+    // It doesn't depend on the substitution costs, so that cheap substitutions,
+    // e.g. of a `CostMatrix`, don't make transpositions too cheap for the unrestricted distance.
+    fn transposition(&self, _first: &T, _second: &T) -> C {
+        max_cost(self.insertion, self.deletion)
+    }
+
+    // 2 * max(insertion, deletion) >= insertion + deletion, so both distances are valid.
+    fn validate(&self, _restricted: bool) -> Result<(), Error> {
+        LevenshteinWeights::validate::<T>(self)
+    }
+
+    fn max_distance(&self, src_len: usize, tar_len: usize) -> C {
+        LevenshteinWeights::max_distance::<T>(self, src_len, tar_len)
+    }
+}
+
+/// Costs of the edit operations used by [`WeightedDamerauLevenshtein`],
+/// with a separate cost for transposing two adjacent characters.
+/// Substitution can have a single cost, or a cost for each pair of characters
+/// given by a [`SubstitutionCost`] such as [`CostMatrix`](crate::CostMatrix).
+///
+/// The unrestricted distance requires the transposition to cost at least half
+/// as much as an insertion and a deletion together (`2 * transposition >= insertion + deletion`),
+/// otherwise it could be cheaper to transpose characters which are far apart than to move them.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::DamerauLevenshteinWeights;
+///
+/// let typos = DamerauLevenshteinWeights::new(1.0, 1.0, 1.0, 0.5);
+///
+/// assert_eq!(DamerauLevenshteinWeights::new(1, 1, 1, 1), DamerauLevenshteinWeights::default());
+/// assert_eq!(0.5, typos.transposition);
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamerauLevenshteinWeights<C = usize, Sub = C> {
+    /// Cost of inserting a character
+    pub insertion: C,
+    /// Cost of deleting a character
+    pub deletion: C,
+    /// Cost of replacing a character with a different one
+    pub substitution: Sub,
+    /// Cost of swapping two adjacent characters
+    pub transposition: C,
+}
+
+impl<C: Cost, Sub> DamerauLevenshteinWeights<C, Sub> {
+    /// Create weights from the costs of insertion, deletion, substitution and transposition.
+    pub fn new(insertion: C, deletion: C, substitution: Sub, transposition: C) -> Self {
+        DamerauLevenshteinWeights {
+            insertion,
+            deletion,
+            substitution,
+            transposition,
+        }
+    }
+}
+
+impl Default for DamerauLevenshteinWeights<usize> {
+    fn default() -> Self {
+        DamerauLevenshteinWeights::new(1, 1, 1, 1)
+    }
+}
+
+impl<T, C: Cost, Sub: SubstitutionCost<T, Cost = C>> DamerauLevenshteinCosts<T> for DamerauLevenshteinWeights<C, Sub> {
+    type Cost = C;
+
+    fn insertion(&self) -> C {
+        self.insertion
+    }
+
+    fn deletion(&self) -> C {
+        self.deletion
+    }
+
+    fn substitution(&self, a: &T, b: &T) -> C {
+        self.substitution.substitution(a, b)
+    }
+
+    fn transposition(&self, _first: &T, _second: &T) -> C {
+        self.transposition
+    }
+
+    fn validate(&self, restricted: bool) -> Result<(), Error> {
+        validate_costs([
+            ("insertion", self.insertion),
+            ("deletion", self.deletion),
+            ("substitution", self.substitution.max_substitution()),
+            ("transposition", self.transposition),
+        ])?;
+
+        if !restricted && self.transposition + self.transposition < self.insertion + self.deletion {
+            return Err(Error::InvalidParameter {
+                name: "transposition",
+                reason: format!(
+                    "the unrestricted distance requires 2 * transposition >= insertion + deletion, got {:?}",
+                    self.transposition
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    // transpositions only make distances shorter, so the largest distance is the `Levenshtein` one
+    fn max_distance(&self, src_len: usize, tar_len: usize) -> C {
+        levenshtein_max_distance(
+            self.insertion,
+            self.deletion,
+            self.substitution.max_substitution(),
+            src_len,
+            tar_len,
+        )
+    }
+}
+
+/// Calculate the `Weighted Damerau-Levenshtein` distance between two strings.
+/// It is the `Damerau-Levenshtein` distance where insertion, deletion, substitution
+/// and transposition each have their own cost, given by [`DamerauLevenshteinCosts`].
+/// With [`LevenshteinWeights`] transposing two adjacent characters `ab` into `ba` costs as much as the larger of an insertion and a deletion,
+/// with [`DamerauLevenshteinWeights`] it has a cost of its own.
+/// With unit weights it is equal to the `Damerau-Levenshtein` distance.
+/// The normalized distance is relative to the largest `Weighted Levenshtein` distance,
+/// since transpositions can only make the distance shorter.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{LevenshteinWeights, WeightedDamerauLevenshtein};
+///
+/// let weighted_damerau_levenshtein = WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)};
+///
+/// assert_eq!(4, weighted_damerau_levenshtein.distance());
+/// assert_eq!(0.5, weighted_damerau_levenshtein.normalized_distance());
+/// assert_eq!(4, weighted_damerau_levenshtein.similarity());
+/// assert_eq!(0.5, weighted_damerau_levenshtein.normalized_similarity());
+///
+/// ```
+///
+/// Substitution costs for each pair of characters, e.g. for neighbouring keys:
+///
+/// ```
+/// use text_distance::{CostMatrix, LevenshteinWeights, WeightedDamerauLevenshtein};
+///
+/// let keyboard = CostMatrix::parse("q w 0.5\nw e 0.5\ne r 0.5", 1.0).unwrap();
+/// let weighted_damerau_levenshtein = WeightedDamerauLevenshtein {src: "qwerty",  tar: "wwrety", restricted: false, weights: LevenshteinWeights::new(1.0, 1.0, keyboard)};
+///
+/// assert_eq!(1.5, weighted_damerau_levenshtein.distance());
+///
+/// ```
+///
+/// Transpositions cheaper than substitutions, e.g. for typing errors:
+///
+/// ```
+/// use text_distance::{DamerauLevenshteinWeights, WeightedDamerauLevenshtein};
+///
+/// let weighted_damerau_levenshtein = WeightedDamerauLevenshtein {src: "recieve",  tar: "receive", restricted: true, weights: DamerauLevenshteinWeights::new(1.0, 1.0, 1.0, 0.5)};
+///
+/// assert_eq!(0.5, weighted_damerau_levenshtein.distance());
+/// assert_eq!(0.07142857142857142, weighted_damerau_levenshtein.normalized_distance());
+///
+/// ```
+///
+pub struct WeightedDamerauLevenshtein<S = String, W = LevenshteinWeights> {
+    /// Source string
+    pub src: S,
+    /// Target string
+    pub tar: S,
+    /// * If `restricted = true` it calculates `Optimal String Alignment Distance`.
+    /// * If `restricted = false` it calculates distance with `Adjacent Transpositions`.
+    pub restricted: bool,
+    /// Costs of the edit operations
+    pub weights: W,
+}
+
+impl<S: AsRef<str>, W: DamerauLevenshteinCosts> WeightedDamerauLevenshtein<S, W> {
+    fn max_distance(&self) -> W::Cost {
+        self.weights.max_distance(
+            self.src.as_ref().chars().count(),
+            self.tar.as_ref().chars().count(),
+        )
+    }
+
+    /// Calculate the `Weighted Damerau-Levenshtein` distance between two strings.
+    /// If any weight is negative or the transposition is too cheap for the unrestricted distance, then panic.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedDamerauLevenshtein};
+    ///
+    /// let restricted = WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: true, weights: LevenshteinWeights::new(1, 1, 1)};
+    /// let unrestricted = WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(1, 1, 1)};
+    ///
+    /// assert_eq!(3, restricted.distance());
+    /// assert_eq!(2, unrestricted.distance());
+    ///
+    /// ```
+    pub fn distance(&self) -> W::Cost {
+        self.try_distance().expect(INVALID_WEIGHTS)
+    }
+
+    /// Calculate the `Weighted Damerau-Levenshtein` distance between two strings.
+    /// If any weight is negative or the transposition is too cheap for the unrestricted distance, then return [`Error::InvalidParameter`].
+    pub fn try_distance(&self) -> Result<W::Cost, Error> {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        try_generic_weighted_damerau_levenshtein(&src, &tar, self.restricted, &self.weights)
+    }
+
+    /// Calculate the `normalized distance` between two strings.
+    /// The normalized distance is the distance divided by the largest possible distance between strings of these lengths.
+    /// The normalized distance is always between 0.0 and 1.0.
+    /// When 0.0 then two strings are equal.
+    /// When 1.0 then two strings are completely different.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedDamerauLevenshtein};
+    ///
+    /// let weighted_damerau_levenshtein = WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)};
+    ///
+    /// assert_eq!(0.5, weighted_damerau_levenshtein.normalized_distance());
+    ///
+    /// ```
+    pub fn normalized_distance(&self) -> f64 {
+        self.try_normalized_distance().expect(INVALID_WEIGHTS)
+    }
+
+    /// Calculate the `normalized distance` between two strings.
+    /// If any weight is negative or the transposition is too cheap for the unrestricted distance, then return [`Error::InvalidParameter`].
+    pub fn try_normalized_distance(&self) -> Result<f64, Error> {
+        let maximum = self.max_distance();
+        let str_distance = self.try_distance()?;
+        // a substitution function which exceeds its `max` can make the distance larger than the maximum
+        if maximum > W::Cost::zero() {
+            return Ok((str_distance.to_f64() / maximum.to_f64()).min(1.0));
+        }
+
+        Ok(0.0)
+    }
+
+    /// Calculate the `similarity` between two strings.
+    /// The similarity is the largest possible distance minus the distance.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedDamerauLevenshtein};
+    ///
+    /// let weighted_damerau_levenshtein = WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)};
+    ///
+    /// assert_eq!(4, weighted_damerau_levenshtein.similarity());
+    ///
+    /// ```
+    pub fn similarity(&self) -> W::Cost {
+        self.try_similarity().expect(INVALID_WEIGHTS)
+    }
+
+    /// Calculate the `similarity` between two strings.
+    /// If any weight is negative or the transposition is too cheap for the unrestricted distance, then return [`Error::InvalidParameter`].
+    pub fn try_similarity(&self) -> Result<W::Cost, Error> {
+        let maximum = self.max_distance();
+        let str_distance = self.try_distance()?;
+
+        Ok(saturating_sub(maximum, str_distance))
+    }
+
+    /// Calculate the `normalized similarity` between two strings.
+    /// The normalized similarity is 1 minus normalized distance.
+    /// The normalized similarity is always between 0.0 and 1.0.
+    /// When 0.0 then two strings are completely different.
+    /// When 1.0 then two strings are equal.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedDamerauLevenshtein};
+    ///
+    /// let weighted_damerau_levenshtein = WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)};
+    ///
+    /// assert_eq!(0.5, weighted_damerau_levenshtein.normalized_similarity());
+    ///
+    /// ```
+    pub fn normalized_similarity(&self) -> f64 {
+        self.try_normalized_similarity().expect(INVALID_WEIGHTS)
+    }
+
+    /// Calculate the `normalized similarity` between two strings.
+    /// If any weight is negative or the transposition is too cheap for the unrestricted distance, then return [`Error::InvalidParameter`].
+    pub fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        let str_normalized_distance = self.try_normalized_distance()?;
+
+        Ok(1.0 - str_normalized_distance)
+    }
+}
+
+fn restricted_distance<T: Eq, W: DamerauLevenshteinCosts<T>>(src: &[T], tar: &[T], weights: &W) -> W::Cost {
+    // only three rows of the matrix are kept, so they should be as short as possible:
+    // transforming `tar` into `src` with the inverse operations costs the same
+    if tar.len() > src.len() {
+        three_rows_distance(
+            tar,
+            src,
+            weights.insertion(),
+            weights.deletion(),
+            |a, b| weights.substitution(b, a),
+            |first, second| weights.transposition(second, first),
+        )
+    } else {
+        three_rows_distance(
+            src,
+            tar,
+            weights.deletion(),
+            weights.insertion(),
+            |a, b| weights.substitution(a, b),
+            |first, second| weights.transposition(first, second),
+        )
+    }
+}
+
+fn three_rows_distance<T: Eq, C: Cost>(
+    src: &[T],
+    tar: &[T],
+    deletion_cost: C,
+    insertion_cost: C,
+    substitution: impl Fn(&T, &T) -> C,
+    transposition: impl Fn(&T, &T) -> C,
+) -> C {
+    let mut previous: Vec<C> = Vec::with_capacity(tar.len() + 1);
+    previous.push(C::zero());
+    for j in 0..tar.len() {
+        previous.push(previous[j] + insertion_cost);
+    }
+    let mut before_previous = previous.clone();
+    let mut current = previous.clone();
+
+    for (i, s_item) in src.iter().enumerate() {
+        current[0] = previous[0] + deletion_cost;
+        for (j, t_item) in tar.iter().enumerate() {
+            let substitution_cost = if s_item == t_item {
+                C::zero()
+            } else {
+                substitution(s_item, t_item)
+            };
+            let deletion = previous[j + 1] + deletion_cost;
+            let insertion = current[j] + insertion_cost;
+            let substitution = previous[j] + substitution_cost;
+
+            current[j + 1] = min_cost(min_cost(deletion, insertion), substitution);
+
+            // transposition
+            if i > 0 && j > 0 && s_item != t_item && *s_item == tar[j - 1] && *t_item == src[i - 1] {
+                current[j + 1] = min_cost(
+                    current[j + 1],                                                 // cost without swappping
+                    before_previous[j - 1] + transposition(&src[i - 1], s_item), // cost with swapping
+                );
+            }
+        }
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[tar.len()]
+}
+
+fn unrestricted_distance<T: Eq + Hash, W: DamerauLevenshteinCosts<T>>(src: &[T], tar: &[T], weights: &W) -> W::Cost {
+    let (deletion_cost, insertion_cost) = (weights.deletion(), weights.insertion());
+    // last row where each element of the alphabet was seen in the source
+    let alphabet = Alphabet::new(src, tar);
+    let mut da: Vec<usize> = vec![0; alphabet.len];
+    let mut matrix: Vec<Vec<W::Cost>> = vec![vec![W::Cost::zero(); tar.len() + 1]; src.len() + 1];
+
+    for i in 1..(src.len() + 1) {
+        matrix[i][0] = matrix[i - 1][0] + deletion_cost;
+    }
+
+    for j in 1..(tar.len() + 1) {
+        matrix[0][j] = matrix[0][j - 1] + insertion_cost;
+    }
+
+    for i in 1..(src.len() + 1) {
+        // last column where the target matched src[i - 1]
+        let mut db = 0;
+        for j in 1..(tar.len() + 1) {
+            let k = da[alphabet.tar[j - 1]];
+            let l = db;
+
+            let matched = src[i - 1] == tar[j - 1];
+            let substitution_cost = if matched {
+                db = j;
+                W::Cost::zero()
+            } else {
+                weights.substitution(&src[i - 1], &tar[j - 1])
+            };
+            let deletion = matrix[i - 1][j] + deletion_cost;
+            let insertion = matrix[i][j - 1] + insertion_cost;
+            let substitution = matrix[i - 1][j - 1] + substitution_cost;
+
+            matrix[i][j] = min_cost(min_cost(deletion, insertion), substitution);
+
+            // transposition of src[k - 1] and src[i - 1], with everything between them deleted
+            // and everything between tar[l - 1] and tar[j - 1] inserted
+            if k > 0 && l > 0 && !matched {
+                let transposition = matrix[k - 1][l - 1]
+                    + deletion_cost.times(i - k - 1)
+                    + weights.transposition(&src[k - 1], &src[i - 1])
+                    + insertion_cost.times(j - l - 1);
+                matrix[i][j] = min_cost(matrix[i][j], transposition);
+            }
+        }
+        da[alphabet.src[i - 1]] = i;
+    }
+
+    matrix[src.len()][tar.len()]
+}
+
+/// Calculate the `Weighted Damerau-Levenshtein` distance between two sequences of arbitrary elements.
+/// If any weight is negative or the transposition is too cheap for the unrestricted distance, then panic.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{generic_weighted_damerau_levenshtein, DamerauLevenshteinWeights, LevenshteinWeights};
+///
+/// assert_eq!(1.0, generic_weighted_damerau_levenshtein(&[3, 1, 4], &[1, 3, 4], true, &LevenshteinWeights::new(1.0, 1.0, 0.5)));
+/// assert_eq!(0.75, generic_weighted_damerau_levenshtein(&[3, 1, 4], &[1, 3, 4], false, &DamerauLevenshteinWeights::new(1.0, 0.5, 1.0, 0.75)));
+///
+/// ```
+pub fn generic_weighted_damerau_levenshtein<T: Eq + Hash, W: DamerauLevenshteinCosts<T>>(
+    src: &[T],
+    tar: &[T],
+    restricted: bool,
+    weights: &W,
+) -> W::Cost {
+    try_generic_weighted_damerau_levenshtein(src, tar, restricted, weights).expect(INVALID_WEIGHTS)
+}
+
+/// Calculate the `Weighted Damerau-Levenshtein` distance between two sequences of arbitrary elements.
+/// If any weight is negative or the transposition is too cheap for the unrestricted distance,
+/// then return [`Error::InvalidParameter`].
+pub fn try_generic_weighted_damerau_levenshtein<T: Eq + Hash, W: DamerauLevenshteinCosts<T>>(
+    src: &[T],
+    tar: &[T],
+    restricted: bool,
+    weights: &W,
+) -> Result<W::Cost, Error> {
+    weights.validate(restricted)?;
+
+    if restricted {
+        Ok(restricted_distance(src, tar, weights))
+    } else {
+        Ok(unrestricted_distance(src, tar, weights))
+    }
+}
+
+impl<S: AsRef<str>, W: DamerauLevenshteinCosts> StringMetric for WeightedDamerauLevenshtein<S, W> {
+    type Output = W::Cost;
+
+    fn distance(&self) -> W::Cost {
+        WeightedDamerauLevenshtein::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        WeightedDamerauLevenshtein::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> W::Cost {
+        WeightedDamerauLevenshtein::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        WeightedDamerauLevenshtein::normalized_similarity(self)
+    }
+
+    fn try_distance(&self) -> Result<W::Cost, Error> {
+        WeightedDamerauLevenshtein::try_distance(self)
+    }
+
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        WeightedDamerauLevenshtein::try_normalized_distance(self)
+    }
+
+    fn try_similarity(&self) -> Result<W::Cost, Error> {
+        WeightedDamerauLevenshtein::try_similarity(self)
+    }
+
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        WeightedDamerauLevenshtein::try_normalized_similarity(self)
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{Error, StringMetric, Tokenizer};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+const INVALID_INPUT: &str = "Can't create tokens from the text, or a token weight is negative";
+
+/// The weight of each token used by [`WeightedJaccard`].
+/// It is implemented for `HashMap<String, f64>`, where tokens missing from the map have weight 1.0,
+/// and for [`Idf`], which derives the weights from the frequency of tokens in a corpus.
+pub trait TokenWeights {
+    /// The weight of a single occurrence of `token`. It must not be negative.
+    fn weight(&self, token: &str) -> f64;
+}
+
+impl<W: TokenWeights + ?Sized> TokenWeights for &W {
+    fn weight(&self, token: &str) -> f64 {
+        (**self).weight(token)
+    }
+}
+
+impl TokenWeights for HashMap<String, f64> {
+    fn weight(&self, token: &str) -> f64 {
+        self.get(token).copied().unwrap_or(1.0)
+    }
+}
+
+/// Inverse document frequency of tokens in a corpus, so that rare tokens weigh more than common ones.
+/// The weight of a token which occurs in `df` of `n` documents is `ln((1 + n) / (1 + df)) + 1`,
+/// tokens which occur in no document have the weight of `df = 0`.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{Idf, TokenWeights, Whitespace};
+///
+/// let idf = Idf::new(&["the cat sat", "the dog sat", "the cat ran"], &Whitespace).unwrap();
+///
+/// assert_eq!(1.0, idf.weight("the"));
+/// assert_eq!(1.2876820724517808, idf.weight("cat"));
+/// assert_eq!(2.386294361119891, idf.weight("bird"));
+///
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Idf {
+    weights: HashMap<String, f64>,
+    unseen: f64,
+}
+
+impl Idf {
+    /// Count the documents each token occurs in.
+    /// If a document can't be tokenized, then return the [`Error`] of the tokenizer.
+    pub fn new<D: AsRef<str>, Q: Tokenizer>(documents: &[D], tokenizer: &Q) -> Result<Self, Error> {
+        let mut document_frequency: HashMap<String, usize> = HashMap::new();
+        for document in documents {
+            let tokens: HashSet<String> = tokenizer.tokenize(document.as_ref())?.into_iter().collect();
+            for token in tokens {
+                *document_frequency.entry(token).or_insert(0) += 1;
+            }
+        }
+
+        let idf = |df: usize| ((1 + documents.len()) as f64 / (1 + df) as f64).ln() + 1.0;
+        Ok(Idf {
+            weights: document_frequency
+                .into_iter()
+                .map(|(token, df)| (token, idf(df)))
+                .collect(),
+            unseen: idf(0),
+        })
+    }
+}
+
+impl TokenWeights for Idf {
+    fn weight(&self, token: &str) -> f64 {
+        self.weights.get(token).copied().unwrap_or(self.unseen)
+    }
+}
+
+/// Calculate the `Weighted Jaccard` (`Ruzicka`) index between two strings.
+/// Every occurrence of a token adds its weight, given by [`TokenWeights`], to the token's total in the string.
+/// The index is the sum of the smaller totals over the sum of the larger totals.
+/// With unit weights it is equal to the [`BagJaccard`](crate::BagJaccard) index.
+/// If both sums are zero, e.g. both strings are empty, then the strings are identical and the index is 1.
+/// [For more information see wikipedia article](https://en.wikipedia.org/wiki/Jaccard_index#Weighted_Jaccard_similarity_and_distance)
+///
+/// ### Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use text_distance::WeightedJaccard;
+///
+/// let stop_words = HashMap::from([("the".to_string(), 0.1)]);
+/// let weighted_jaccard = WeightedJaccard {src: "the cat".to_string(),  tar: "the dog".to_string(), qval: 0, weights: stop_words};
+///
+/// assert_eq!(0.9523809523809523, weighted_jaccard.distance());
+/// assert_eq!(0.9523809523809523, weighted_jaccard.normalized_distance());
+/// assert_eq!(0.04761904761904767, weighted_jaccard.similarity());
+/// assert_eq!(0.04761904761904767, weighted_jaccard.normalized_similarity());
+///
+/// ```
+///
+/// Weights derived from a corpus:
+///
+/// ```
+/// use text_distance::{Idf, WeightedJaccard, Whitespace};
+///
+/// let idf = Idf::new(&["the cat sat", "the dog sat", "the cat ran"], &Whitespace).unwrap();
+/// let weighted_jaccard = WeightedJaccard {src: "the cat sat",  tar: "the dog sat", qval: Whitespace, weights: &idf};
+///
+/// assert_eq!(0.5657820717979537, weighted_jaccard.distance());
+///
+/// ```
+///
+pub struct WeightedJaccard<S = String, Q = usize, W = HashMap<String, f64>> {
+    /// Source string
+    pub src: S,
+    /// Target string
+    pub tar: S,
+    /// q-gram value, or any other [`Tokenizer`].
+    /// * If `qval = 0` then q-grams are words
+    /// * If `qval = 1` then q-grams are chars
+    /// * If `qval > 1` then q-grams are ngrams of `qval` chars
+    pub qval: Q,
+    /// Weights of the tokens
+    pub weights: W,
+}
+
+impl<S: AsRef<str>, Q: Tokenizer, W: TokenWeights> WeightedJaccard<S, Q, W> {
+    /// Calculate the `Weighted Jaccard` distance between two strings.
+    /// The distance is 1 minus the weighted index.
+    /// The distance is always between 0.0 and 1.0.
+    /// When 0.0 then two strings have the same tokens, each as many times.
+    /// When 1.0 then two strings have no token of positive weight in common.
+    /// If a string can't be tokenized or a weight is negative, then panic.
+    pub fn distance(&self) -> f64 {
+        self.try_distance().expect(INVALID_INPUT)
+    }
+
+    /// Calculate the `Weighted Jaccard` distance between two strings.
+    /// If a string can't be tokenized, e.g. it is shorter than `qval`, then return the [`Error`] of the tokenizer.
+    /// If a weight is negative, then return [`Error::InvalidParameter`].
+    pub fn try_distance(&self) -> Result<f64, Error> {
+        let src_tokens = self.qval.tokenize(self.src.as_ref())?;
+        let tar_tokens = self.qval.tokenize(self.tar.as_ref())?;
+
+        try_generic_weighted_jaccard(&src_tokens, &tar_tokens, |token| self.weights.weight(token))
+    }
+
+    /// Calculate the `normalized distance`.
+    /// The distance is already between 0.0 and 1.0, so it is equal to the distance.
+    pub fn normalized_distance(&self) -> f64 {
+        self.try_normalized_distance().expect(INVALID_INPUT)
+    }
+
+    /// Calculate the `normalized distance`.
+    /// If a string can't be tokenized or a weight is negative, then return an [`Error`].
+    pub fn try_normalized_distance(&self) -> Result<f64, Error> {
+        self.try_distance()
+    }
+
+    /// Calculate the `similarity`.
+    /// The similarity is the weighted index, i.e. 1 minus the distance.
+    pub fn similarity(&self) -> f64 {
+        self.try_similarity().expect(INVALID_INPUT)
+    }
+
+    /// Calculate the `similarity`.
+    /// If a string can't be tokenized or a weight is negative, then return an [`Error`].
+    pub fn try_similarity(&self) -> Result<f64, Error> {
+        let str_distance = self.try_distance()?;
+
+        Ok(1.0 - str_distance)
+    }
+
+    /// Calculate the `normalized similarity`.
+    /// The normalized similarity is 1 minus normalized distance.
+    pub fn normalized_similarity(&self) -> f64 {
+        self.try_normalized_similarity().expect(INVALID_INPUT)
+    }
+
+    /// Calculate the `normalized similarity`.
+    /// If a string can't be tokenized or a weight is negative, then return an [`Error`].
+    pub fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        let str_normalized_distance = self.try_normalized_distance()?;
+
+        Ok(1.0 - str_normalized_distance)
+    }
+}
+
+/// Calculate the `Weighted Jaccard` distance between two collections of arbitrary tokens,
+/// where `weight` gives the weight of a single occurrence of a token.
+/// If a weight is negative, then panic.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::generic_weighted_jaccard;
+///
+/// assert_eq!(0.75, generic_weighted_jaccard(&[1, 2, 2], &[2, 3], |&token| token as f64));
+///
+/// ```
+pub fn generic_weighted_jaccard<T: Eq + Hash>(src: &[T], tar: &[T], weight: impl Fn(&T) -> f64) -> f64 {
+    try_generic_weighted_jaccard(src, tar, weight).expect(INVALID_INPUT)
+}
+
+/// Calculate the `Weighted Jaccard` distance between two collections of arbitrary tokens.
+/// If a weight is negative, then return [`Error::InvalidParameter`].
+pub fn try_generic_weighted_jaccard<T: Eq + Hash>(
+    src: &[T],
+    tar: &[T],
+    weight: impl Fn(&T) -> f64,
+) -> Result<f64, Error> {
+    weighted_jaccard_of_counts(src, tar, |token| {
+        let token_weight = weight(token);
+        // NaN is rejected as well
+        if token_weight >= 0.0 {
+            Ok(token_weight)
+        } else {
+            Err(Error::InvalidParameter {
+                name: "weights",
+                reason: format!("token weight must not be negative, got {:?}", token_weight),
+            })
+        }
+    })
+}
+
+pub(crate) fn weighted_jaccard_of_counts<T: Eq + Hash>(
+    src: &[T],
+    tar: &[T],
+    weight: impl Fn(&T) -> Result<f64, Error>,
+) -> Result<f64, Error> {
+    // the counts are kept in the order the tokens first occur, so that the sums don't depend on hashing
+    let mut indices: HashMap<&T, usize> = HashMap::new();
+    let mut counts: Vec<(&T, usize, usize)> = Vec::new();
+    for (token, in_src) in src.iter().map(|token| (token, true)).chain(tar.iter().map(|token| (token, false))) {
+        let index = *indices.entry(token).or_insert_with(|| {
+            counts.push((token, 0, 0));
+            counts.len() - 1
+        });
+        if in_src {
+            counts[index].1 += 1;
+        } else {
+            counts[index].2 += 1;
+        }
+    }
+
+    let mut intersection = 0.0;
+    let mut union = 0.0;
+    for (token, src_count, tar_count) in counts {
+        let token_weight = weight(token)?;
+        intersection += token_weight * src_count.min(tar_count) as f64;
+        union += token_weight * src_count.max(tar_count) as f64;
+    }
+
+    // without any weight, e.g. for two empty inputs, nothing tells the inputs apart
+    if union == 0.0 {
+        return Ok(0.0);
+    }
+
+    Ok(1.0 - intersection / union)
+}
+
+impl<S: AsRef<str>, Q: Tokenizer, W: TokenWeights> StringMetric for WeightedJaccard<S, Q, W> {
+    type Output = f64;
+
+    fn distance(&self) -> f64 {
+        WeightedJaccard::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        WeightedJaccard::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> f64 {
+        WeightedJaccard::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        WeightedJaccard::normalized_similarity(self)
+    }
+
+    fn try_distance(&self) -> Result<f64, Error> {
+        WeightedJaccard::try_distance(self)
+    }
+
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        WeightedJaccard::try_normalized_distance(self)
+    }
+
+    fn try_similarity(&self) -> Result<f64, Error> {
+        WeightedJaccard::try_similarity(self)
+    }
+
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        WeightedJaccard::try_normalized_similarity(self)
+    }
+}
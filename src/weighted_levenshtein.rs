@@ -0,0 +1,397 @@
+use crate::cost::{min_cost, saturating_sub, validate_costs};
+use crate::{Cost, Error, StringMetric, SubstitutionCost};
+
+const INVALID_WEIGHTS: &str = "Edit operation weights must not be negative";
+
+/// Costs of the edit operations used by [`WeightedLevenshtein`].
+/// The costs can be integers (`usize`) or floating-point numbers (`f64`).
+/// Substitution can have a single cost, or a cost for each pair of characters
+/// given by a [`SubstitutionCost`] such as [`CostMatrix`](crate::CostMatrix).
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::LevenshteinWeights;
+///
+/// let unit = LevenshteinWeights::default();
+/// let ocr = LevenshteinWeights::new(1.0, 0.5, 0.8);
+///
+/// assert_eq!(LevenshteinWeights::new(1, 1, 1), unit);
+/// assert_eq!(0.5, ocr.deletion);
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevenshteinWeights<C = usize, Sub = C> {
+    /// Cost of inserting a character
+    pub insertion: C,
+    /// Cost of deleting a character
+    pub deletion: C,
+    /// Cost of replacing a character with a different one
+    pub substitution: Sub,
+}
+
+impl<C: Cost, Sub> LevenshteinWeights<C, Sub> {
+    /// Create weights from the costs of insertion, deletion and substitution.
+    pub fn new(insertion: C, deletion: C, substitution: Sub) -> Self {
+        LevenshteinWeights {
+            insertion,
+            deletion,
+            substitution,
+        }
+    }
+
+    /// Check that none of the costs is negative (or `NaN`).
+    pub(crate) fn validate<T>(&self) -> Result<(), Error>
+    where
+        Sub: SubstitutionCost<T, Cost = C>,
+    {
+        validate_costs([
+            ("insertion", self.insertion),
+            ("deletion", self.deletion),
+            ("substitution", self.substitution.max_substitution()),
+        ])
+    }
+
+    /// The largest possible distance between sequences of the given lengths,
+    /// i.e. the cost of transforming them when no element matches.
+    pub(crate) fn max_distance<T>(&self, src_len: usize, tar_len: usize) -> C
+    where
+        Sub: SubstitutionCost<T, Cost = C>,
+    {
+        levenshtein_max_distance(
+            self.insertion,
+            self.deletion,
+            self.substitution.max_substitution(),
+            src_len,
+            tar_len,
+        )
+    }
+}
+
+/// The largest possible `Weighted Levenshtein` distance between sequences of the given lengths.
+pub(crate) fn levenshtein_max_distance<C: Cost>(
+    insertion: C,
+    deletion: C,
+    max_substitution: C,
+    src_len: usize,
+    tar_len: usize,
+) -> C {
+    let common_len = src_len.min(tar_len);
+    let delete_and_insert = deletion.times(src_len) + insertion.times(tar_len);
+    let substitute = max_substitution.times(common_len)
+        + deletion.times(src_len - common_len)
+        + insertion.times(tar_len - common_len);
+
+    min_cost(delete_and_insert, substitute)
+}
+
+impl Default for LevenshteinWeights<usize> {
+    fn default() -> Self {
+        LevenshteinWeights::new(1, 1, 1)
+    }
+}
+
+/// Calculate the `Weighted Levenshtein` distance between two strings.
+/// It is the `Levenshtein` distance where insertion, deletion and substitution
+/// each have their own cost, given by [`LevenshteinWeights`].
+/// With unit weights it is equal to the `Levenshtein` distance.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{LevenshteinWeights, WeightedLevenshtein};
+///
+/// let weighted_levenshtein = WeightedLevenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string(), weights: LevenshteinWeights::new(2, 1, 3)};
+///
+/// assert_eq!(6, weighted_levenshtein.distance());
+/// assert_eq!(0.2857142857142857, weighted_levenshtein.normalized_distance());
+/// assert_eq!(15, weighted_levenshtein.similarity());
+/// assert_eq!(0.7142857142857143, weighted_levenshtein.normalized_similarity());
+///
+/// ```
+///
+/// Floating-point costs:
+///
+/// ```
+/// use text_distance::{LevenshteinWeights, WeightedLevenshtein};
+///
+/// let weighted_levenshtein = WeightedLevenshtein {src: "rn",  tar: "m", weights: LevenshteinWeights::new(1.0, 0.25, 1.0)};
+///
+/// assert_eq!(1.25, weighted_levenshtein.distance());
+///
+/// ```
+///
+/// Substitution costs for each pair of characters:
+///
+/// ```
+/// use text_distance::{CostMatrix, LevenshteinWeights, WeightedLevenshtein};
+///
+/// let ocr = CostMatrix::parse("O 0 0.1\nl 1 0.2", 1.0).unwrap();
+/// let weighted_levenshtein = WeightedLevenshtein {src: "C0FFEE l0VER",  tar: "COFFEE lOVER", weights: LevenshteinWeights::new(1.0, 1.0, ocr)};
+///
+/// assert_eq!(0.2, weighted_levenshtein.distance());
+///
+/// ```
+///
+pub struct WeightedLevenshtein<S = String, C = usize, Sub = C> {
+    /// Source string
+    pub src: S,
+    /// Target string
+    pub tar: S,
+    /// Costs of the edit operations
+    pub weights: LevenshteinWeights<C, Sub>,
+}
+
+impl<S: AsRef<str>, C: Cost, Sub: SubstitutionCost<Cost = C>> WeightedLevenshtein<S, C, Sub> {
+    fn max_distance(&self) -> C {
+        self.weights.max_distance::<char>(
+            self.src.as_ref().chars().count(),
+            self.tar.as_ref().chars().count(),
+        )
+    }
+
+    /// Calculate the `Weighted Levenshtein` distance between two strings.
+    /// The distance is the minimum total cost of the edit operations needed to transform the source string into the target string.
+    /// If any weight is negative, then panic.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedLevenshtein};
+    ///
+    /// let weighted_levenshtein = WeightedLevenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string(), weights: LevenshteinWeights::new(2, 1, 3)};
+    ///
+    /// assert_eq!(6, weighted_levenshtein.distance());
+    ///
+    /// ```
+    pub fn distance(&self) -> C {
+        self.try_distance().expect(INVALID_WEIGHTS)
+    }
+
+    /// Calculate the `Weighted Levenshtein` distance between two strings.
+    /// If any weight is negative, then return [`Error::InvalidParameter`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedLevenshtein};
+    ///
+    /// let weighted_levenshtein = WeightedLevenshtein {src: "karolin",  tar: "kathrin", weights: LevenshteinWeights::new(1.0, -1.0, 1.0)};
+    ///
+    /// assert!(weighted_levenshtein.try_distance().is_err());
+    ///
+    /// ```
+    pub fn try_distance(&self) -> Result<C, Error> {
+        let src: Vec<char> = self.src.as_ref().chars().collect();
+        let tar: Vec<char> = self.tar.as_ref().chars().collect();
+
+        try_generic_weighted_levenshtein(&src, &tar, &self.weights)
+    }
+
+    /// Calculate the `normalized distance` between two strings.
+    /// The normalized distance is the distance divided by the largest possible distance between strings of these lengths,
+    /// i.e. the cost of transforming them when no character matches.
+    /// The normalized distance is always between 0.0 and 1.0.
+    /// When 0.0 then two strings are equal.
+    /// When 1.0 then two strings are completely different.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedLevenshtein};
+    ///
+    /// let weighted_levenshtein = WeightedLevenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string(), weights: LevenshteinWeights::new(2, 1, 3)};
+    ///
+    /// assert_eq!(0.2857142857142857, weighted_levenshtein.normalized_distance());
+    ///
+    /// ```
+    pub fn normalized_distance(&self) -> f64 {
+        self.try_normalized_distance().expect(INVALID_WEIGHTS)
+    }
+
+    /// Calculate the `normalized distance` between two strings.
+    /// If any weight is negative, then return [`Error::InvalidParameter`].
+    pub fn try_normalized_distance(&self) -> Result<f64, Error> {
+        let maximum = self.max_distance();
+        let str_distance = self.try_distance()?;
+        // a substitution function which exceeds its `max` can make the distance larger than the maximum
+        if maximum > C::zero() {
+            return Ok((str_distance.to_f64() / maximum.to_f64()).min(1.0));
+        }
+
+        Ok(0.0)
+    }
+
+    /// Calculate the `similarity` between two strings.
+    /// The similarity is the largest possible distance minus the distance.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedLevenshtein};
+    ///
+    /// let weighted_levenshtein = WeightedLevenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string(), weights: LevenshteinWeights::new(2, 1, 3)};
+    ///
+    /// assert_eq!(15, weighted_levenshtein.similarity());
+    ///
+    /// ```
+    pub fn similarity(&self) -> C {
+        self.try_similarity().expect(INVALID_WEIGHTS)
+    }
+
+    /// Calculate the `similarity` between two strings.
+    /// If any weight is negative, then return [`Error::InvalidParameter`].
+    pub fn try_similarity(&self) -> Result<C, Error> {
+        let maximum = self.max_distance();
+        let str_distance = self.try_distance()?;
+
+        Ok(saturating_sub(maximum, str_distance))
+    }
+
+    /// Calculate the `normalized similarity` between two strings.
+    /// The normalized similarity is 1 minus normalized distance.
+    /// The normalized similarity is always between 0.0 and 1.0.
+    /// When 0.0 then two strings are completely different.
+    /// When 1.0 then two strings are equal.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use text_distance::{LevenshteinWeights, WeightedLevenshtein};
+    ///
+    /// let weighted_levenshtein = WeightedLevenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string(), weights: LevenshteinWeights::new(2, 1, 3)};
+    ///
+    /// assert_eq!(0.7142857142857143, weighted_levenshtein.normalized_similarity());
+    ///
+    /// ```
+    pub fn normalized_similarity(&self) -> f64 {
+        self.try_normalized_similarity().expect(INVALID_WEIGHTS)
+    }
+
+    /// Calculate the `normalized similarity` between two strings.
+    /// If any weight is negative, then return [`Error::InvalidParameter`].
+    pub fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        let str_normalized_distance = self.try_normalized_distance()?;
+
+        Ok(1.0 - str_normalized_distance)
+    }
+}
+
+/// Calculate the `Weighted Levenshtein` distance between two sequences of arbitrary elements.
+/// If any weight is negative, then panic.
+///
+/// ### Examples
+///
+/// ```
+/// use text_distance::{generic_weighted_levenshtein, LevenshteinWeights};
+///
+/// let src: Vec<&str> = "the cat sat on the mat".split_whitespace().collect();
+/// let tar: Vec<&str> = "the cat on the mat".split_whitespace().collect();
+///
+/// assert_eq!(0.5, generic_weighted_levenshtein(&src, &tar, &LevenshteinWeights::new(1.0, 0.5, 1.0)));
+///
+/// ```
+pub fn generic_weighted_levenshtein<T: Eq, C: Cost, Sub: SubstitutionCost<T, Cost = C>>(
+    src: &[T],
+    tar: &[T],
+    weights: &LevenshteinWeights<C, Sub>,
+) -> C {
+    try_generic_weighted_levenshtein(src, tar, weights).expect(INVALID_WEIGHTS)
+}
+
+/// Calculate the `Weighted Levenshtein` distance between two sequences of arbitrary elements.
+/// If any weight is negative, then return [`Error::InvalidParameter`].
+pub fn try_generic_weighted_levenshtein<T: Eq, C: Cost, Sub: SubstitutionCost<T, Cost = C>>(
+    src: &[T],
+    tar: &[T],
+    weights: &LevenshteinWeights<C, Sub>,
+) -> Result<C, Error> {
+    weights.validate::<T>()?;
+
+    // only two rows of the matrix are kept, so they should be as short as possible:
+    // transforming `tar` into `src` with the inverse operations costs the same
+    let distance = if tar.len() > src.len() {
+        two_rows_distance(tar, src, weights.insertion, weights.deletion, |a, b| {
+            weights.substitution.substitution(b, a)
+        })
+    } else {
+        two_rows_distance(src, tar, weights.deletion, weights.insertion, |a, b| {
+            weights.substitution.substitution(a, b)
+        })
+    };
+
+    Ok(distance)
+}
+
+fn two_rows_distance<T: Eq, C: Cost>(
+    src: &[T],
+    tar: &[T],
+    deletion_cost: C,
+    insertion_cost: C,
+    substitution: impl Fn(&T, &T) -> C,
+) -> C {
+    let mut previous: Vec<C> = Vec::with_capacity(tar.len() + 1);
+    previous.push(C::zero());
+    for j in 0..tar.len() {
+        previous.push(previous[j] + insertion_cost);
+    }
+    let mut current = previous.clone();
+
+    // apply edit operations
+    for s_item in src {
+        current[0] = previous[0] + deletion_cost;
+        for (j, t_item) in tar.iter().enumerate() {
+            let substitution_cost = if s_item == t_item {
+                C::zero()
+            } else {
+                substitution(s_item, t_item)
+            };
+            let deletion = previous[j + 1] + deletion_cost;
+            let insertion = current[j] + insertion_cost;
+            let substitution = previous[j] + substitution_cost;
+
+            current[j + 1] = min_cost(min_cost(deletion, insertion), substitution);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[tar.len()]
+}
+
+impl<S: AsRef<str>, C: Cost, Sub: SubstitutionCost<Cost = C>> StringMetric
+    for WeightedLevenshtein<S, C, Sub>
+{
+    type Output = C;
+
+    fn distance(&self) -> C {
+        WeightedLevenshtein::distance(self)
+    }
+
+    fn normalized_distance(&self) -> f64 {
+        WeightedLevenshtein::normalized_distance(self)
+    }
+
+    fn similarity(&self) -> C {
+        WeightedLevenshtein::similarity(self)
+    }
+
+    fn normalized_similarity(&self) -> f64 {
+        WeightedLevenshtein::normalized_similarity(self)
+    }
+
+    fn try_distance(&self) -> Result<C, Error> {
+        WeightedLevenshtein::try_distance(self)
+    }
+
+    fn try_normalized_distance(&self) -> Result<f64, Error> {
+        WeightedLevenshtein::try_normalized_distance(self)
+    }
+
+    fn try_similarity(&self) -> Result<C, Error> {
+        WeightedLevenshtein::try_similarity(self)
+    }
+
+    fn try_normalized_similarity(&self) -> Result<f64, Error> {
+        WeightedLevenshtein::try_normalized_similarity(self)
+    }
+}
@@ -0,0 +1,33 @@
+// Helpers shared by the integration tests.
+// Each test crate uses only some of them.
+#![allow(dead_code)]
+
+/// Linear congruential generator, so that the random inputs are the same on every run.
+pub struct Lcg(u32);
+
+impl Lcg {
+    pub fn new(seed: u32) -> Self {
+        Lcg(seed)
+    }
+
+    pub fn next(&mut self) -> usize {
+        self.0 = self.0.wrapping_mul(1103515245).wrapping_add(12345);
+        (self.0 >> 16) as usize
+    }
+
+    /// A string of `len` chars picked from `alphabet`.
+    pub fn string(&mut self, len: usize, alphabet: &[char]) -> String {
+        (0..len).map(|_| alphabet[self.next() % alphabet.len()]).collect()
+    }
+}
+
+/// `count` strings shorter than `max_len` chars picked from `alphabet`.
+pub fn random_strings(seed: u32, count: usize, max_len: usize, alphabet: &[char]) -> Vec<String> {
+    let mut lcg = Lcg::new(seed);
+    (0..count)
+        .map(|_| {
+            let len = lcg.next() % max_len;
+            lcg.string(len, alphabet)
+        })
+        .collect()
+}
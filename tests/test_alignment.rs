@@ -0,0 +1,122 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{random_strings, Lcg};
+    use text_distance::{generic_damerau_levenshtein_edit_script, generic_levenshtein_edit_script, Alignment, DamerauLevenshtein, EditOperation, Levenshtein};
+
+    // Small strings over a tiny alphabet, so that transpositions and repeated characters are common
+    fn strings() -> Vec<String> {
+        let mut strings = vec![String::new()];
+        strings.extend(random_strings(7, 40, 7, &['a', 'b', 'c']));
+        strings
+    }
+
+    fn without_gaps(aligned: &str) -> String {
+        aligned.chars().filter(|&c| c != Alignment::GAP).collect()
+    }
+
+    #[test]
+    fn test_levenshtein_edit_script() {
+        assert_eq!(Vec::<EditOperation>::new(), Levenshtein {src: "",  tar: ""}.edit_script());
+        assert_eq!(vec![EditOperation::Delete {src_pos: 0}, EditOperation::Delete {src_pos: 1}], Levenshtein {src: "ab",  tar: ""}.edit_script());
+        assert_eq!(
+            vec![
+                EditOperation::Substitute {src_pos: 0, tar_pos: 0},
+                EditOperation::Keep {src_pos: 1, tar_pos: 1},
+                EditOperation::Keep {src_pos: 2, tar_pos: 2},
+                EditOperation::Keep {src_pos: 3, tar_pos: 3},
+                EditOperation::Substitute {src_pos: 4, tar_pos: 4},
+                EditOperation::Keep {src_pos: 5, tar_pos: 5},
+                EditOperation::Insert {tar_pos: 6},
+            ],
+            Levenshtein {src: "kitten",  tar: "sitting"}.edit_script()
+        );
+        assert_eq!(vec![EditOperation::Keep {src_pos: 0, tar_pos: 0}, EditOperation::Substitute {src_pos: 1, tar_pos: 1}], generic_levenshtein_edit_script(&[1, 2], &[1, 3]));
+    }
+
+    #[test]
+    fn test_damerau_levenshtein_edit_script() {
+        assert_eq!(
+            vec![EditOperation::Insert {tar_pos: 0}, EditOperation::Substitute {src_pos: 0, tar_pos: 1}, EditOperation::Substitute {src_pos: 1, tar_pos: 2}],
+            DamerauLevenshtein {src: "ca",  tar: "abc", restricted: true}.edit_script()
+        );
+        assert_eq!(
+            vec![EditOperation::Keep {src_pos: 0, tar_pos: 0}, EditOperation::Transpose {src_pos: 1, tar_pos: 1}],
+            DamerauLevenshtein {src: "abc",  tar: "acb", restricted: true}.edit_script()
+        );
+        assert_eq!(
+            vec![EditOperation::Transpose {src_pos: 0, tar_pos: 0}, EditOperation::Delete {src_pos: 1}],
+            generic_damerau_levenshtein_edit_script(&['a', 'x', 'b'], &['b', 'a'], false)
+        );
+        assert_eq!(
+            vec![EditOperation::Transpose {src_pos: 0, tar_pos: 0}, EditOperation::Insert {tar_pos: 1}, EditOperation::Insert {tar_pos: 2}],
+            generic_damerau_levenshtein_edit_script(&['a', 'b'], &['b', 'y', 'z', 'a'], false)
+        );
+    }
+
+    #[test]
+    fn test_alignment() {
+        let alignment = DamerauLevenshtein {src: "axb",  tar: "ba", restricted: false}.alignment();
+        assert_eq!("axb", alignment.src);
+        assert_eq!("b-a", alignment.tar);
+        assert_eq!(2, alignment.distance());
+        assert_eq!("axb\nb-a", alignment.to_string());
+
+        let alignment = DamerauLevenshtein {src: "ab",  tar: "byza", restricted: false}.alignment();
+        assert_eq!("a--b", alignment.src);
+        assert_eq!("byza", alignment.tar);
+
+        let alignment = DamerauLevenshtein {src: "abcd",  tar: "bacd", restricted: true}.alignment();
+        assert_eq!("abcd", alignment.src);
+        assert_eq!("bacd", alignment.tar);
+        assert_eq!(1, alignment.distance());
+
+        let alignment = Levenshtein {src: "სახლი",  tar: "სახელი"}.alignment();
+        assert_eq!("სახ-ლი", alignment.src);
+        assert_eq!("სახელი", alignment.tar);
+    }
+
+    #[test]
+    fn test_consistency() {
+        let strings = strings();
+        for src in &strings {
+            for tar in &strings {
+                let levenshtein = Levenshtein {src, tar};
+                let alignment = levenshtein.alignment();
+                assert_eq!(levenshtein.distance(), alignment.distance());
+                assert_eq!((src.clone(), tar.clone()), (without_gaps(&alignment.src), without_gaps(&alignment.tar)));
+                assert!(alignment.operations.iter().all(|op| !matches!(op, EditOperation::Transpose {..})));
+
+                for restricted in [true, false] {
+                    let damerau_levenshtein = DamerauLevenshtein {src, tar, restricted};
+                    let alignment = damerau_levenshtein.alignment();
+                    assert_eq!(damerau_levenshtein.distance(), alignment.distance());
+                    assert_eq!(alignment.src.chars().count(), alignment.tar.chars().count());
+                    assert_eq!((src.clone(), tar.clone()), (without_gaps(&alignment.src), without_gaps(&alignment.tar)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_strings() {
+        // large enough to be aligned with Hirschberg's algorithm instead of a full matrix
+        let mut lcg = Lcg::new(11);
+        let mut text = |len: usize| lcg.string(len, &['a', 'b', 'c', 'd']);
+        let (long, short) = (text(1500), text(1200));
+
+        for (src, tar) in [(&long, &short), (&short, &long)] {
+            let levenshtein = Levenshtein {src, tar};
+            let alignment = levenshtein.alignment();
+            assert_eq!(levenshtein.distance(), alignment.distance());
+            assert_eq!(alignment.src.chars().count(), alignment.tar.chars().count());
+            assert_eq!((src.clone(), tar.clone()), (without_gaps(&alignment.src), without_gaps(&alignment.tar)));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{generic_bag_jaccard, BagJaccard, CharNgrams, Error, Jaccard, Words};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0.33333333333333337, BagJaccard {src: "the the cat".to_string(),  tar: "the cat".to_string(), qval: 0}.distance());
+        assert_eq!(0.5714285714285714, BagJaccard {src: "aabbc".to_string(),  tar: "abbbd".to_string(), qval: 1}.distance());
+        assert_eq!(0.4285714285714286, BagJaccard {src: "banana".to_string(),  tar: "bandana".to_string(), qval: 2}.distance());
+        assert_eq!(0.0, BagJaccard {src: "the cat".to_string(),  tar: "cat the".to_string(), qval: 0}.distance());
+        assert_eq!(1.0, BagJaccard {src: "abc".to_string(),  tar: "xyz".to_string(), qval: 1}.distance());
+    }
+
+    #[test]
+    fn test_normalized_distance() {
+        assert_eq!(0.33333333333333337, BagJaccard {src: "the the cat".to_string(),  tar: "the cat".to_string(), qval: 0}.normalized_distance());
+        assert_eq!(0.5714285714285714, BagJaccard {src: "aabbc".to_string(),  tar: "abbbd".to_string(), qval: 1}.normalized_distance());
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(0.6666666666666666, BagJaccard {src: "the the cat".to_string(),  tar: "the cat".to_string(), qval: 0}.similarity());
+        assert_eq!(0.4285714285714286, BagJaccard {src: "aabbc".to_string(),  tar: "abbbd".to_string(), qval: 1}.similarity());
+    }
+
+    #[test]
+    fn test_normalized_similarity() {
+        assert_eq!(0.6666666666666666, BagJaccard {src: "the the cat".to_string(),  tar: "the cat".to_string(), qval: 0}.normalized_similarity());
+        assert_eq!(0.5714285714285714, BagJaccard {src: "banana".to_string(),  tar: "bandana".to_string(), qval: 2}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_set_and_bag() {
+        assert_eq!(0.0, Jaccard {src: "the the cat",  tar: "the cat", qval: 0}.distance());
+        assert_eq!(0.33333333333333337, BagJaccard {src: "the the cat",  tar: "the cat", qval: 0}.distance());
+        // without repeated tokens both are the same
+        assert_eq!(
+            Jaccard {src: "karolin",  tar: "kathrin", qval: 1}.distance(),
+            BagJaccard {src: "karolin",  tar: "kathrin", qval: 1}.distance()
+        );
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        assert_eq!(0.5, BagJaccard {src: "The cat, the cat!",  tar: "the cat", qval: Words::default()}.distance());
+        assert_eq!(0.4285714285714286, BagJaccard {src: "banana",  tar: "bandana", qval: CharNgrams {n: 2}}.distance());
+        assert_eq!(Err(Error::InputTooShort {len: 6, min_len: 7}), BagJaccard {src: "banana",  tar: "bandana", qval: 7}.try_distance());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't create tokens from the text")]
+    fn test_bag_jaccard_panic() {
+        BagJaccard {src: "nelson",  tar: "neilsen", qval: 100}.distance();
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(0.5, generic_bag_jaccard(&["ab", "bb", "ba", "ab"], &["ab", "ba"]));
+        assert_eq!(0.5, generic_bag_jaccard(&[1, 1, 2], &[1, 2, 2]));
+        assert_eq!(1.0, generic_bag_jaccard(&[1, 1], &[2]));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(0.0, BagJaccard {src: "".to_string(),  tar: "".to_string(), qval: 0}.distance());
+        assert_eq!(1.0, BagJaccard {src: "".to_string(),  tar: "".to_string(), qval: 0}.similarity());
+        assert_eq!(1.0, BagJaccard {src: "".to_string(),  tar: "the cat".to_string(), qval: 0}.distance());
+        assert_eq!(0.0, generic_bag_jaccard::<u8>(&[], &[]));
+    }
+}
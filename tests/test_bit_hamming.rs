@@ -0,0 +1,100 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{bit_hamming, bits_from_binary, bits_from_hex, generic_bit_hamming, try_generic_bit_hamming, BitWord, Error};
+
+    // count differing bits one by one
+    fn naive_bit_hamming(src: &[u8], tar: &[u8]) -> usize {
+        src.iter()
+            .zip(tar)
+            .map(|(s_byte, t_byte)| (0..8).filter(|bit| (s_byte >> bit) & 1 != (t_byte >> bit) & 1).count())
+            .sum()
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(0, bit_hamming(0xabu8, 0xab));
+        assert_eq!(8, bit_hamming(0u8, u8::MAX));
+        assert_eq!(16, bit_hamming(0u16, u16::MAX));
+        assert_eq!(1, bit_hamming(1u32 << 31, 0));
+        assert_eq!(4, bit_hamming(0xf0u64, 0xff));
+        assert_eq!(128, bit_hamming(0u128, u128::MAX));
+        assert_eq!(64, <u64 as BitWord>::BITS);
+    }
+
+    #[test]
+    fn test_byte_slices() {
+        // lengths around the chunks of 8 bytes
+        let src: Vec<u8> = (0..=40u8).map(|i| i.wrapping_mul(37)).collect();
+        let tar: Vec<u8> = (0..=40u8).map(|i| i.wrapping_mul(91) ^ 0x5a).collect();
+        for len in 0..=src.len() {
+            assert_eq!(naive_bit_hamming(&src[..len], &tar[..len]), generic_bit_hamming(&src[..len], &tar[..len]));
+        }
+        assert_eq!(0, generic_bit_hamming::<u8>(&[], &[]));
+    }
+
+    #[test]
+    fn test_word_slices() {
+        let src = [0u64, u64::MAX, 0xf0f0, 1 << 63];
+        let tar = [u64::MAX, u64::MAX, 0x0f0f, 0];
+        assert_eq!(81, generic_bit_hamming(&src, &tar));
+
+        // the same 256 bits as bytes
+        let src_bytes: Vec<u8> = src.iter().flat_map(|word| word.to_be_bytes()).collect();
+        let tar_bytes: Vec<u8> = tar.iter().flat_map(|word| word.to_be_bytes()).collect();
+        assert_eq!(generic_bit_hamming(&src, &tar), generic_bit_hamming(&src_bytes, &tar_bytes));
+    }
+
+    #[test]
+    fn test_try_generic() {
+        assert_eq!(Ok(2), try_generic_bit_hamming(&[0b11u128], &[0]));
+        assert_eq!(Err(Error::LengthMismatch {src_len: 3, tar_len: 2}), try_generic_bit_hamming(&[1u8, 2, 3], &[1, 2]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_generic_panics() {
+        generic_bit_hamming(&[1u64, 2], &[1]);
+    }
+
+    #[test]
+    fn test_from_hex() {
+        let fingerprint = "d1c3b5a7e9f80213_4657687980a1b2c3_d4e5f60718293a4b_5c6d7e8f90a1b2c3";
+        let words: Vec<u64> = bits_from_hex(fingerprint).unwrap();
+        let bytes: Vec<u8> = bits_from_hex(fingerprint).unwrap();
+        let halves: Vec<u128> = bits_from_hex(fingerprint).unwrap();
+        assert_eq!(vec![0xd1c3b5a7e9f80213, 0x4657687980a1b2c3, 0xd4e5f60718293a4b, 0x5c6d7e8f90a1b2c3], words);
+        assert_eq!(vec![0xd1, 0xc3, 0xb5, 0xa7], bytes[..4].to_vec());
+        assert_eq!(0xd1c3b5a7e9f802134657687980a1b2c3, halves[0]);
+        assert_eq!(bytes, words.iter().flat_map(|word| word.to_be_bytes()).collect::<Vec<u8>>());
+
+        assert_eq!(Ok(vec![0xABCDu16]), bits_from_hex("0xABCD"));
+        assert_eq!(Ok(vec![0xABCDu16]), bits_from_hex("0XABCD"));
+        assert_eq!(Ok(Vec::<u64>::new()), bits_from_hex(""));
+    }
+
+    #[test]
+    fn test_from_binary() {
+        assert_eq!(Ok(vec![0b1010_1010u8, 0b0000_0001]), bits_from_binary("0b10101010_00000001"));
+        assert_eq!(Ok(vec![0b1010_1010_0000_0001u16]), bits_from_binary("1010101000000001"));
+        assert_eq!(Ok(vec![0b1010_1010u8]), bits_from_binary("0B1010_1010"));
+        assert_eq!(Ok(vec![1u64 << 63]), bits_from_binary(&format!("1{}", "0".repeat(63))));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(Error::Parse {line: 1, reason: "expected a digit in base 16, got 'g'".to_string()}), bits_from_hex::<u8>("0xfg"));
+        assert_eq!(Err(Error::Parse {line: 1, reason: "expected a digit in base 2, got '2'".to_string()}), bits_from_binary::<u8>("00000002"));
+        assert_eq!(Err(Error::Parse {line: 1, reason: "expected a multiple of 16 digits, got 8".to_string()}), bits_from_hex::<u64>("deadbeef"));
+        assert_eq!(Err(Error::Parse {line: 1, reason: "expected a multiple of 8 digits, got 4".to_string()}), bits_from_binary::<u8>("1010"));
+        // a sign is not a digit
+        assert!(bits_from_hex::<u8>("+f").is_err());
+        assert!(bits_from_hex::<u8>("0x0xff").is_err());
+        assert!(bits_from_hex::<u8>("0X0Xff").is_err());
+        assert!(bits_from_binary::<u8>("0b0B00000000").is_err());
+    }
+}
@@ -0,0 +1,72 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{generic_cosine, CharNgrams, Cosine, Error};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0.3675444679663241, Cosine {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.distance());
+        assert_eq!(0.28995305319530695, Cosine {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.distance());
+        assert_eq!(0.4479475525261166, Cosine {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.distance());
+    }
+
+    #[test]
+    fn test_normalized_distance() {
+        assert_eq!(0.3675444679663241, Cosine {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.normalized_distance());
+        assert_eq!(0.28995305319530695, Cosine {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.normalized_distance());
+        assert_eq!(0.4479475525261166, Cosine {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.normalized_distance());
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(0.6324555320336759, Cosine {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.similarity());
+        assert_eq!(0.710046946804693, Cosine {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.similarity());
+        assert_eq!(0.5520524474738834, Cosine {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.similarity());
+    }
+
+    #[test]
+    fn test_normalized_similarity() {
+        assert_eq!(0.6324555320336759, Cosine {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.normalized_similarity());
+        assert_eq!(0.710046946804693, Cosine {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.normalized_similarity());
+        assert_eq!(0.5520524474738834, Cosine {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(0.38278660015163235, Cosine {src: "ქართული",  tar: "ქართველი", qval: 2}.distance());
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        assert_eq!(
+            Cosine {src: "nelson has a car",  tar: "neilsen has a cat", qval: 2}.distance(),
+            Cosine {src: "nelson has a car",  tar: "neilsen has a cat", qval: CharNgrams {n: 2}}.distance()
+        );
+        assert_eq!(Err(Error::InputTooShort {len: 6, min_len: 7}), Cosine {src: "nelson",  tar: "neilsen", qval: 7}.try_distance());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't create tokens from the text")]
+    fn test_cosine_panic() {
+        Cosine {src: "nelson",  tar: "neilsen", qval: 100}.distance();
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(0.5917517095361369, generic_cosine(&[1, 2, 3], &[3, 4]));
+        assert_eq!(0.5, generic_cosine(&[1, 2, 3, 4], &[1]));
+        assert_eq!(0.0, generic_cosine(&["a", "b"], &["b", "a", "a"]));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(0.0, Cosine {src: "",  tar: "", qval: 0}.distance());
+        assert_eq!(1.0, Cosine {src: "",  tar: "abc", qval: 0}.distance());
+        assert_eq!(0.0, Cosine {src: "abc",  tar: "", qval: 1}.similarity());
+        assert_eq!(1.0, generic_cosine::<u8>(&[], &[1, 2]));
+    }
+}
@@ -0,0 +1,79 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{CostMatrix, Error, SubstitutionCost, SubstitutionFn};
+
+    #[test]
+    fn test_cost_matrix() {
+        let mut matrix = CostMatrix::new(2).unwrap();
+        matrix.insert('a', 'b', 1).unwrap();
+        matrix.insert_symmetric('c', 'd', 0).unwrap();
+        assert_eq!(1, matrix.get('a', 'b'));
+        assert_eq!(2, matrix.get('b', 'a'));
+        assert_eq!(0, matrix.get('c', 'd'));
+        assert_eq!(0, matrix.get('d', 'c'));
+        assert_eq!(2, matrix.max_substitution());
+    }
+
+    #[test]
+    fn test_max_substitution() {
+        let mut matrix = CostMatrix::new(1.0).unwrap();
+        assert_eq!(1.0, matrix.max_substitution());
+        matrix.insert('a', 'b', 0.5).unwrap();
+        matrix.insert('c', 'd', 2.0).unwrap();
+        matrix.insert('e', 'f', 3.0).unwrap();
+        assert_eq!(3.0, matrix.max_substitution());
+        // lowering the largest cost makes the next one the largest
+        matrix.insert('e', 'f', 0.1).unwrap();
+        assert_eq!(2.0, matrix.max_substitution());
+        matrix.insert('c', 'd', 0.1).unwrap();
+        assert_eq!(1.0, matrix.max_substitution());
+    }
+
+    #[test]
+    fn test_invalid_costs() {
+        assert!(matches!(CostMatrix::new(-1.0), Err(Error::InvalidParameter {name: "default", ..})));
+        assert!(matches!(CostMatrix::new(f64::NAN), Err(Error::InvalidParameter {name: "default", ..})));
+        assert!(matches!(CostMatrix::parse("O 0 0.2", -1.0), Err(Error::InvalidParameter {name: "default", ..})));
+
+        let mut matrix = CostMatrix::new(1.0).unwrap();
+        matrix.insert('a', 'b', 0.5).unwrap();
+        assert!(matches!(matrix.insert('a', 'b', -0.5), Err(Error::InvalidParameter {name: "cost", ..})));
+        assert!(matches!(matrix.insert_symmetric('c', 'd', f64::NAN), Err(Error::InvalidParameter {name: "cost", ..})));
+        // rejected costs are not added
+        assert_eq!(0.5, matrix.get('a', 'b'));
+        assert_eq!(1.0, matrix.get('c', 'd'));
+        assert_eq!(1.0, matrix.max_substitution());
+    }
+
+    #[test]
+    fn test_parse() {
+        let matrix = CostMatrix::parse("# comment\n\nO 0 0.2\n  l  1  0.3  \nა ბ 1.5\n", 1.0).unwrap();
+        assert_eq!(0.2, matrix.substitution(&'O', &'0'));
+        assert_eq!(0.2, matrix.substitution(&'0', &'O'));
+        assert_eq!(0.3, matrix.substitution(&'1', &'l'));
+        assert_eq!(1.5, matrix.substitution(&'ბ', &'ა'));
+        assert_eq!(1.0, matrix.substitution(&'x', &'y'));
+        assert_eq!(1.5, matrix.max_substitution());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(Error::Parse {line: 2, reason: "expected `a b cost`".to_string()}), CostMatrix::<f64>::parse("a b 1\na b", 1.0));
+        assert_eq!(Err(Error::Parse {line: 1, reason: "expected a single character".to_string()}), CostMatrix::<f64>::parse("rn m 1", 1.0));
+        assert_eq!(Err(Error::Parse {line: 3, reason: "expected a number".to_string()}), CostMatrix::<usize>::parse("a b 1\n\nc d 0.5", 1));
+        assert_eq!(Err(Error::Parse {line: 1, reason: "cost must not be negative".to_string()}), CostMatrix::<f64>::parse("a b -1", 1.0));
+    }
+
+    #[test]
+    fn test_substitution_fn() {
+        let vowels = SubstitutionFn {function: |a: &char, b: &char| if "aeiou".contains(*a) && "aeiou".contains(*b) { 1 } else { 3 }, max: 3};
+        assert_eq!(1, vowels.substitution(&'a', &'o'));
+        assert_eq!(3, vowels.substitution(&'a', &'b'));
+        assert_eq!(3, vowels.max_substitution());
+    }
+}
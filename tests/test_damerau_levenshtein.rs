@@ -4,9 +4,14 @@
 // https://docs.rs/approx/latest/approx/
 
 
+mod common;
+
 #[cfg(test)]
 mod tests {
-    use text_distance::DamerauLevenshtein;
+    use crate::common::random_strings;
+    use text_distance::{generic_damerau_levenshtein, generic_damerau_levenshtein_within, DamerauLevenshtein, LevenshteinWeights, WeightedDamerauLevenshtein};
+
+    const ALPHABET: [char; 5] = ['a', 'b', 'c', 'd', 'ა'];
 
     // restricted
     #[test]
@@ -73,4 +78,73 @@ mod tests {
         assert_eq!(0.16666666666666663, DamerauLevenshtein {src: "abcdef".to_string(),  tar: "axb123".to_string(), restricted: false}.normalized_similarity());
         assert_eq!(0.33333333333333337, DamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false}.normalized_similarity());
     }
+
+    #[test]
+    fn test_borrowed() {
+        assert_eq!(3, DamerauLevenshtein {src: "ca",  tar: "abc", restricted: true}.distance());
+        assert_eq!(2, DamerauLevenshtein {src: "ca",  tar: "abc", restricted: false}.distance());
+        assert_eq!(0.6666666666666666, DamerauLevenshtein {src: "ca",  tar: "abc", restricted: false}.normalized_distance());
+    }
+
+    #[test]
+    fn test_generic() {
+        let src: Vec<&str> = "new york city".split_whitespace().collect();
+        let tar: Vec<&str> = "york new city".split_whitespace().collect();
+        assert_eq!(1, generic_damerau_levenshtein(&src, &tar, true));
+        assert_eq!(1, generic_damerau_levenshtein(&src, &tar, false));
+        assert_eq!(3, generic_damerau_levenshtein(&[2, 0], &[0, 1, 2], true));
+        assert_eq!(2, generic_damerau_levenshtein(&[2, 0], &[0, 1, 2], false));
+    }
+
+    #[test]
+    fn test_distance_within() {
+        assert_eq!(Some(3), DamerauLevenshtein {src: "ca",  tar: "abc", restricted: true}.distance_within(3));
+        assert_eq!(None, DamerauLevenshtein {src: "ca",  tar: "abc", restricted: true}.distance_within(2));
+        assert_eq!(Some(2), DamerauLevenshtein {src: "ca",  tar: "abc", restricted: false}.distance_within(2));
+        assert_eq!(None, DamerauLevenshtein {src: "ca",  tar: "abc", restricted: false}.distance_within(1));
+        assert_eq!(None, DamerauLevenshtein {src: "test",  tar: "", restricted: false}.distance_within(3));
+
+        let words = ["", "a", "ab", "ba", "abc", "acb", "cab", "ca", "abcdef", "badcfe", "specter", "spectre", "levenshtein", "levnehstien"];
+        for src in words {
+            for tar in words {
+                for restricted in [true, false] {
+                    let distance = generic_damerau_levenshtein(src.as_bytes(), tar.as_bytes(), restricted);
+                    for max in 0..8 {
+                        let expected = if distance <= max { Some(distance) } else { None };
+                        assert_eq!(expected, generic_damerau_levenshtein_within(src.as_bytes(), tar.as_bytes(), restricted, max), "{} {} {}", src, tar, max);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_strings() {
+        let strings = random_strings(7, 30, 300, &ALPHABET);
+        for src in &strings {
+            for tar in &strings {
+                let damerau_levenshtein = DamerauLevenshtein {src, tar, restricted: true};
+                // the edit script is backtracked through the full matrix
+                let expected = damerau_levenshtein.edit_script().iter().filter(|op| op.is_edit()).count();
+                assert_eq!(expected, damerau_levenshtein.distance());
+            }
+        }
+    }
+
+    #[test]
+    fn test_unrestricted_long_strings() {
+        let strings = random_strings(3, 15, 150, &ALPHABET);
+        for src in &strings {
+            for tar in &strings {
+                let damerau_levenshtein = DamerauLevenshtein {src, tar, restricted: false};
+                let weighted = WeightedDamerauLevenshtein {src, tar, restricted: false, weights: LevenshteinWeights::default()};
+                assert_eq!(weighted.distance(), damerau_levenshtein.distance());
+            }
+        }
+
+        // 2000 characters each, which used to take seconds
+        let src: String = "abcdefghij".repeat(200);
+        let tar: String = "bacdefghji".repeat(200);
+        assert_eq!(400, DamerauLevenshtein {src: &src, tar: &tar, restricted: false}.distance());
+    }
 }
@@ -0,0 +1,74 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{generic_dice, CharNgrams, Dice, Error};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0.3846153846153846, Dice {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.distance());
+        assert_eq!(0.29032258064516125, Dice {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.distance());
+        assert_eq!(0.4482758620689655, Dice {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.distance());
+    }
+
+    #[test]
+    fn test_normalized_distance() {
+        assert_eq!(0.3846153846153846, Dice {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.normalized_distance());
+        assert_eq!(0.29032258064516125, Dice {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.normalized_distance());
+        assert_eq!(0.4482758620689655, Dice {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.normalized_distance());
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(0.6153846153846154, Dice {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.similarity());
+        assert_eq!(0.7096774193548387, Dice {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.similarity());
+        assert_eq!(0.5517241379310345, Dice {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.similarity());
+    }
+
+    #[test]
+    fn test_normalized_similarity() {
+        assert_eq!(0.6153846153846154, Dice {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.normalized_similarity());
+        assert_eq!(0.7096774193548387, Dice {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.normalized_similarity());
+        assert_eq!(0.5517241379310345, Dice {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(0.3846153846153846, Dice {src: "ქართული",  tar: "ქართველი", qval: 2}.distance());
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        assert_eq!(
+            Dice {src: "nelson has a car",  tar: "neilsen has a cat", qval: 2}.distance(),
+            Dice {src: "nelson has a car",  tar: "neilsen has a cat", qval: CharNgrams {n: 2}}.distance()
+        );
+        assert_eq!(Err(Error::InputTooShort {len: 6, min_len: 7}), Dice {src: "nelson",  tar: "neilsen", qval: 7}.try_distance());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't create tokens from the text")]
+    fn test_dice_panic() {
+        Dice {src: "nelson",  tar: "neilsen", qval: 100}.distance();
+    }
+
+    #[test]
+    fn test_generic() {
+        let src: Vec<&str> = "data is the new oil of the digital economy".split_whitespace().collect();
+        let tar: Vec<&str> = "data is a new oil".split_whitespace().collect();
+        assert_eq!(0.3846153846153846, generic_dice(&src, &tar));
+        assert_eq!(0.6, generic_dice(&[1, 2, 3], &[3, 4]));
+        assert_eq!(0.0, generic_dice(&[1, 1, 2], &[2, 1]));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(0.0, Dice {src: "",  tar: "", qval: 0}.distance());
+        assert_eq!(1.0, Dice {src: "",  tar: "", qval: 1}.similarity());
+        assert_eq!(1.0, Dice {src: "",  tar: "abc", qval: 0}.distance());
+        assert_eq!(0.0, generic_dice::<u8>(&[], &[]));
+    }
+}
@@ -6,7 +6,7 @@
 
 #[cfg(test)]
 mod tests {
-    use text_distance::Hamming;
+    use text_distance::{generic_hamming, try_generic_hamming, Error, Hamming};
 
     #[test]
     fn test_distance() {
@@ -45,4 +45,27 @@ mod tests {
     fn test_hamming_panic() {
         Hamming {src: "test".to_string(),  tar: "textt".to_string()}.distance();
     }
+
+    #[test]
+    fn test_borrowed() {
+        assert_eq!(2, Hamming {src: "ijk",  tar: "kji"}.distance());
+        assert_eq!(0.33333333333333337, Hamming {src: "ijk",  tar: "kji"}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(2, generic_hamming(&[true, false, true, true], &[true, true, true, false]));
+        assert_eq!(3, generic_hamming("karolin".as_bytes(), "kathrin".as_bytes()));
+        assert_eq!(0, generic_hamming::<u64>(&[], &[]));
+    }
+
+    #[test]
+    fn test_try_distance() {
+        assert_eq!(Ok(3), Hamming {src: "karolin".to_string(),  tar: "kathrin".to_string()}.try_distance());
+        assert_eq!(Err(Error::LengthMismatch {src_len: 4, tar_len: 5}), Hamming {src: "test".to_string(),  tar: "textt".to_string()}.try_distance());
+        assert_eq!(Err(Error::LengthMismatch {src_len: 0, tar_len: 1}), Hamming {src: "",  tar: "a"}.try_normalized_distance());
+        assert_eq!(Err(Error::LengthMismatch {src_len: 1, tar_len: 0}), Hamming {src: "a",  tar: ""}.try_similarity());
+        assert_eq!(Ok(0.33333333333333337), Hamming {src: "ijk",  tar: "kji"}.try_normalized_similarity());
+        assert_eq!(Err(Error::LengthMismatch {src_len: 3, tar_len: 2}), try_generic_hamming(&[1, 2, 3], &[1, 2]));
+    }
 }
@@ -6,7 +6,7 @@
 
 #[cfg(test)]
 mod tests {
-    use text_distance::Jaccard;
+    use text_distance::{char_ngrams, generic_jaccard, Error, Jaccard};
 
     #[test]
     fn test_distance() {
@@ -41,8 +41,73 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Can't create n-grams from text shorter than n-gram length")]
+    #[should_panic(expected = "Can't create tokens from the text")]
     fn test_jaccard_panic() {
         Jaccard {src: "nelson".to_string(),  tar: "neilsen".to_string(), qval: 100}.distance();
     }
+
+    #[test]
+    fn test_borrowed() {
+        assert_eq!(0.33333333333333337, Jaccard {src: "nelson",  tar: "neilsen", qval: 1}.distance());
+        assert_eq!(0.55, Jaccard {src: "nelson has a car",  tar: "neilsen has a cat", qval: 2}.similarity());
+    }
+
+    #[test]
+    fn test_generic() {
+        let src: Vec<&str> = "data is the new oil of the digital economy".split_whitespace().collect();
+        let tar: Vec<&str> = "data is a new oil".split_whitespace().collect();
+        assert_eq!(0.5555555555555556, generic_jaccard(&src, &tar));
+        assert_eq!(0.75, generic_jaccard(&[1, 2, 3], &[3, 4]));
+        assert_eq!(0.0, generic_jaccard(&[1, 1, 2], &[2, 1]));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(0.0, Jaccard {src: "",  tar: "", qval: 0}.distance());
+        assert_eq!(1.0, Jaccard {src: "",  tar: "", qval: 1}.similarity());
+        assert_eq!(1.0, Jaccard {src: "",  tar: "abc", qval: 0}.distance());
+        assert_eq!(0.0, Jaccard {src: "abc",  tar: "", qval: 1}.normalized_similarity());
+        assert_eq!(0.0, generic_jaccard::<u8>(&[], &[]));
+        assert_eq!(1.0, generic_jaccard(&[1, 2], &[]));
+    }
+
+    #[test]
+    fn test_try_distance() {
+        assert_eq!(Ok(0.44999999999999996), Jaccard {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.try_distance());
+        assert_eq!(Err(Error::InputTooShort {len: 6, min_len: 100}), Jaccard {src: "nelson".to_string(),  tar: "neilsen".to_string(), qval: 100}.try_distance());
+        assert_eq!(Err(Error::InputTooShort {len: 2, min_len: 3}), Jaccard {src: "nelson",  tar: "ne", qval: 3}.try_normalized_distance());
+        assert_eq!(Err(Error::InputTooShort {len: 0, min_len: 2}), Jaccard {src: "",  tar: "neilsen", qval: 2}.try_similarity());
+        assert_eq!(Ok(0.6666666666666666), Jaccard {src: "nelson",  tar: "neilsen", qval: 1}.try_normalized_similarity());
+    }
+
+    #[test]
+    fn test_non_ascii_ngrams() {
+        assert_eq!(0.5555555555555556, Jaccard {src: "ქართული",  tar: "ქართველი", qval: 2}.distance());
+        assert_eq!(0.5555555555555556, Jaccard {src: "привет",  tar: "приветствие", qval: 3}.distance());
+        assert_eq!(0.6666666666666667, Jaccard {src: "東京都",  tar: "京都府", qval: 2}.distance());
+        assert_eq!(0.0, Jaccard {src: "სახლი",  tar: "სახლი", qval: 5}.distance());
+        assert_eq!(Err(Error::InputTooShort {len: 5, min_len: 6}), Jaccard {src: "სახლი",  tar: "სახელი", qval: 6}.try_distance());
+    }
+
+    #[test]
+    fn test_char_ngrams() {
+        assert_eq!(Ok(vec!["ქარ".to_string(), "ართ".to_string()]), char_ngrams("ქართ", 3));
+        assert_eq!(Ok(vec!["東京".to_string()]), char_ngrams("東京", 2));
+        assert_eq!(Err(Error::InputTooShort {len: 2, min_len: 3}), char_ngrams("東京", 3));
+        assert!(matches!(char_ngrams("東京", 0), Err(Error::InvalidParameter {name: "n", ..})));
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_grapheme_ngrams() {
+        use text_distance::grapheme_ngrams;
+
+        // "noël" with a combining diaeresis and a family emoji made of four code points joined by zero width joiners
+        let src = "noe\u{308}l";
+        let tar = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+        assert_eq!(Ok(vec!["no".to_string(), "oe\u{308}".to_string(), "e\u{308}l".to_string()]), grapheme_ngrams(src, 2));
+        assert_eq!(Ok(vec![tar.to_string()]), grapheme_ngrams(tar, 1));
+        assert_eq!(Err(Error::InputTooShort {len: 1, min_len: 2}), grapheme_ngrams(tar, 2));
+        assert_eq!(0.33333333333333337, generic_jaccard(&grapheme_ngrams("noe\u{308}l", 2).unwrap(), &grapheme_ngrams("noe\u{308}", 2).unwrap()));
+    }
 }
@@ -4,7 +4,7 @@
 
 #[cfg(test)]
 mod tests {
-    use text_distance::JaroWinkler;
+    use text_distance::{generic_jaro_winkler, generic_jaro_winkler_with, try_generic_jaro_winkler_with, Error, JaroExplanation, JaroWinkler, JaroWinklerParams, Query};
 
     #[test]
     fn test_distance() {
@@ -37,4 +37,185 @@ mod tests {
         assert_eq!(0.9166666666666666, JaroWinkler {src: "frog".to_string(), tar: "fog".to_string(), winklerize: false }.normalized_similarity());
         assert_eq!(0.0, JaroWinkler {src: "fly".to_string(), tar: "ant".to_string(), winklerize: false }.normalized_similarity());
     }
+
+    #[test]
+    fn test_borrowed() {
+        assert_eq!(0.0810185185185186, JaroWinkler {src: "faremviel", tar: "farmville", winklerize: true }.distance());
+        assert_eq!(0.9166666666666666, JaroWinkler {src: "frog", tar: "fog", winklerize: false }.similarity());
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(0.0810185185185186, generic_jaro_winkler("faremviel".as_bytes(), "farmville".as_bytes(), true));
+        assert_eq!(0.08333333333333337, generic_jaro_winkler(&['f', 'r', 'o', 'g'], &['f', 'o', 'g'], false));
+        assert_eq!(1.0, generic_jaro_winkler(&[1, 2, 3], &[4, 5, 6], true));
+    }
+
+    #[test]
+    fn test_default_params() {
+        let params = JaroWinklerParams::default();
+        assert_eq!(JaroWinklerParams {prefix_scale: 0.1, max_prefix_len: 4, boost_threshold: 0.7}, params);
+        assert_eq!(0.0810185185185186, JaroWinkler {src: "faremviel", tar: "farmville", winklerize: true }.distance_with(&params));
+        assert_eq!(0.08333333333333337, JaroWinkler {src: "frog", tar: "fog", winklerize: false }.distance_with(&params));
+        assert_eq!(0.0810185185185186, generic_jaro_winkler_with(b"faremviel", b"farmville", &params));
+    }
+
+    #[test]
+    fn test_custom_params() {
+        let jaro_winkler = JaroWinkler {src: "faremviel", tar: "farmville", winklerize: true };
+        // the common prefix "far" has 3 chars
+        assert_eq!(0.8842592592592592, jaro_winkler.similarity_with(&JaroWinklerParams::new(0.0, 4, 0.7)));
+        assert_eq!(0.9710648148148148, jaro_winkler.similarity_with(&JaroWinklerParams::new(0.25, 4, 0.7)));
+        assert_eq!(0.9421296296296295, jaro_winkler.similarity_with(&JaroWinklerParams::new(0.25, 2, 0.7)));
+        assert_eq!(0.8842592592592592, jaro_winkler.similarity_with(&JaroWinklerParams::new(0.1, 4, 0.9)));
+        assert_eq!(1.0, jaro_winkler.similarity_with(&JaroWinklerParams::new(1.0 / 3.0, 3, 0.0)));
+        assert_eq!(0.0, JaroWinkler {src: "fly", tar: "ant", winklerize: true }.similarity_with(&JaroWinklerParams::new(0.1, 4, 0.0)));
+    }
+
+    #[test]
+    fn test_query_params() {
+        let query = Query::new("faremviel");
+        for params in [JaroWinklerParams::default(), JaroWinklerParams::new(0.25, 4, 0.5), JaroWinklerParams::new(0.5, 1, 0.95)] {
+            assert_eq!(Ok(generic_jaro_winkler_with(b"faremviel", b"farmville", &params)), query.jaro_winkler_with("farmville", &params));
+        }
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let jaro_winkler = JaroWinkler {src: "frog", tar: "fog", winklerize: true };
+        assert!(matches!(jaro_winkler.try_distance_with(&JaroWinklerParams::new(-0.1, 4, 0.7)), Err(Error::InvalidParameter {name: "prefix_scale", ..})));
+        assert!(matches!(jaro_winkler.try_distance_with(&JaroWinklerParams::new(0.3, 4, 0.7)), Err(Error::InvalidParameter {name: "prefix_scale", ..})));
+        assert!(matches!(jaro_winkler.try_similarity_with(&JaroWinklerParams::new(f64::INFINITY, 0, 0.7)), Err(Error::InvalidParameter {name: "prefix_scale", ..})));
+        assert!(matches!(jaro_winkler.try_distance_with(&JaroWinklerParams::new(0.1, 4, 1.5)), Err(Error::InvalidParameter {name: "boost_threshold", ..})));
+        assert!(matches!(try_generic_jaro_winkler_with(b"frog", b"fog", &JaroWinklerParams::new(0.1, 4, f64::NAN)), Err(Error::InvalidParameter {name: "boost_threshold", ..})));
+        assert!(matches!(Query::new("frog").jaro_winkler_with("fog", &JaroWinklerParams::new(0.5, 4, 0.7)), Err(Error::InvalidParameter {name: "prefix_scale", ..})));
+        // not winklerized, but the parameters are still checked
+        assert!(JaroWinkler {src: "frog", tar: "fog", winklerize: false }.try_distance_with(&JaroWinklerParams::new(0.5, 4, 0.7)).is_err());
+        assert_eq!(Ok(0.0), JaroWinkler {src: "ab", tar: "ab", winklerize: true }.try_distance_with(&JaroWinklerParams::new(0.25, 4, 0.7)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_params_panics() {
+        JaroWinkler {src: "frog", tar: "fog", winklerize: true }.distance_with(&JaroWinklerParams::new(0.5, 4, 0.7));
+    }
+
+    // Non-ASCII name pairs with the similarities of a port of the pure Python `jellyfish` implementation,
+    // as (src, tar, Jaro, Jaro-Winkler).
+    const UNICODE_NAMES: [(&str, &str, f64, f64); 25] = [
+        ("გიორგი", "გოგი", 0.8055555555555555, 0.825),
+        ("ნინო", "ნინა", 0.8333333333333334, 0.8833333333333334),
+        ("თამარი", "თამაზი", 0.888888888888889, 0.9333333333333333),
+        ("დავითი", "დათო", 0.75, 0.8),
+        ("ელენე", "ელისო", 0.6, 0.6),
+        ("ლევანი", "ლევან", 0.9444444444444445, 0.9666666666666667),
+        ("მარიამი", "მარიკა", 0.8492063492063492, 0.9095238095238095),
+        ("ზურაბი", "ზაურ", 0.8055555555555555, 0.825),
+        ("ქეთევანი", "ქეთინო", 0.7222222222222222, 0.8055555555555556),
+        ("შოთა", "თოშა", 0.6666666666666666, 0.6666666666666666),
+        ("ანა", "ნანა", 0.8055555555555555, 0.8055555555555555),
+        ("ირაკლი", "ირაკლი ", 0.9523809523809524, 0.9714285714285714),
+        ("ნიკოლოზი", "ნიკოლზოი", 0.9583333333333334, 0.975),
+        ("მართა", "მარათ", 0.9333333333333332, 0.9533333333333333),
+        ("ნატო", "ნატო", 1.0, 1.0),
+        ("ა", "ბ", 0.0, 0.0),
+        ("ა", "ა ", 0.8333333333333334, 0.8500000000000001),
+        ("Александр", "Алехандро", 0.8518518518518517, 0.8962962962962963),
+        ("Дмитрий", "Дмитрии", 0.9047619047619048, 0.9428571428571428),
+        ("Σωκράτης", "Σοκράτης", 0.9166666666666666, 0.9249999999999999),
+        ("José", "Jose", 0.8333333333333334, 0.8833333333333334),
+        ("Zoë", "Zoe", 0.7777777777777777, 0.8222222222222222),
+        ("Zoe\u{308}", "Zo\u{eb}", 0.7222222222222222, 0.7777777777777778),
+        ("Jürgen", "Juergen", 0.7825396825396825, 0.8042857142857143),
+        ("東京都", "京都", 0.0, 0.0),
+    ];
+
+    #[test]
+    fn test_unicode_names() {
+        for (src, tar, jaro, jaro_winkler) in UNICODE_NAMES {
+            assert_eq!(jaro, JaroWinkler {src, tar, winklerize: false }.similarity(), "{} {}", src, tar);
+            assert_eq!(jaro_winkler, JaroWinkler {src, tar, winklerize: true }.similarity(), "{} {}", src, tar);
+            assert_eq!(1.0 - jaro_winkler, Query::new(src).jaro_winkler(tar, true), "{} {}", src, tar);
+            // Jaro is symmetric
+            assert_eq!(jaro, JaroWinkler {src: tar, tar: src, winklerize: false }.similarity(), "{} {}", src, tar);
+        }
+    }
+
+    #[test]
+    fn test_single_chars() {
+        assert_eq!(1.0, JaroWinkler {src: "a", tar: "b", winklerize: true }.distance());
+        assert_eq!(1.0, generic_jaro_winkler(&[1], &[2], false));
+        assert_eq!(0.0, generic_jaro_winkler(&[1], &[1], false));
+        assert_eq!(1.0, Query::new("a").jaro_winkler("b", false));
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = JaroWinkler {src: "dixon", tar: "dicksonx", winklerize: true }.explain();
+        assert_eq!(
+            JaroExplanation {
+                similarity: 0.8133333333333332,
+                jaro: 0.7666666666666666,
+                matches: 4,
+                half_transpositions: 0,
+                src_positions: vec![0, 1, 3, 4],
+                tar_positions: vec![0, 1, 5, 6],
+                match_radius: 3,
+                prefix_len: 2,
+            },
+            explanation
+        );
+
+        // positions are char indices, not byte indices
+        let explanation = JaroWinkler {src: "ნიკოლოზი", tar: "ნიკოლზოი", winklerize: true }.explain();
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7], explanation.src_positions);
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7], explanation.tar_positions);
+        assert_eq!(2, explanation.half_transpositions);
+        assert_eq!(4, explanation.prefix_len);
+    }
+
+    #[test]
+    fn test_explain_prefix() {
+        // Jaro isn't boosted
+        assert_eq!(0, JaroWinkler {src: "frog", tar: "fog", winklerize: false }.explain().prefix_len);
+        assert_eq!(0.9166666666666666, JaroWinkler {src: "frog", tar: "fog", winklerize: false }.explain().similarity);
+        // below the boost threshold
+        let explanation = JaroWinkler {src: "elene", tar: "eliso", winklerize: true }.explain();
+        assert_eq!((0.6, 0.6, 0), (explanation.similarity, explanation.jaro, explanation.prefix_len));
+        // the cap of the prefix
+        assert_eq!(4, JaroWinkler {src: "johnathan", tar: "johnatan", winklerize: true }.explain().prefix_len);
+        assert_eq!(6, JaroWinkler {src: "johnathan", tar: "johnatan", winklerize: true }.explain_with(&JaroWinklerParams::new(0.1, 6, 0.7)).prefix_len);
+        assert!(matches!(JaroWinkler {src: "frog", tar: "fog", winklerize: true }.try_explain_with(&JaroWinklerParams::new(0.5, 4, 0.7)), Err(Error::InvalidParameter {name: "prefix_scale", ..})));
+    }
+
+    #[test]
+    fn test_explain_edge_cases() {
+        let explanation = JaroWinkler {src: "", tar: "", winklerize: true }.explain();
+        assert_eq!((1.0, 0, 0), (explanation.similarity, explanation.matches, explanation.match_radius));
+        let explanation = JaroWinkler {src: "abc", tar: "", winklerize: true }.explain();
+        assert_eq!((0.0, 0), (explanation.similarity, explanation.matches));
+        let explanation = JaroWinkler {src: "abc", tar: "abc", winklerize: true }.explain();
+        assert_eq!((1.0, 3, vec![0, 1, 2]), (explanation.similarity, explanation.matches, explanation.tar_positions));
+        let explanation = JaroWinkler {src: "fly", tar: "ant", winklerize: true }.explain();
+        assert_eq!((0.0, 0, Vec::<usize>::new()), (explanation.similarity, explanation.matches, explanation.src_positions));
+    }
+
+    #[test]
+    fn test_explain_consistent() {
+        for (src, tar, _, _) in UNICODE_NAMES {
+            for winklerize in [false, true] {
+                let explanation = JaroWinkler {src, tar, winklerize }.explain();
+                assert_eq!(JaroWinkler {src, tar, winklerize }.similarity(), explanation.similarity, "{} {}", src, tar);
+                assert_eq!(JaroWinkler {src, tar, winklerize: false }.similarity(), explanation.jaro, "{} {}", src, tar);
+                assert_eq!(explanation.matches, explanation.src_positions.len());
+                assert_eq!(explanation.matches, explanation.tar_positions.len());
+                let src_chars: Vec<char> = src.chars().collect();
+                let tar_chars: Vec<char> = tar.chars().collect();
+                let different = explanation.src_positions.iter().zip(&explanation.tar_positions)
+                    .filter(|(&i, &j)| src_chars[i] != tar_chars[j])
+                    .count();
+                assert_eq!(explanation.half_transpositions, different, "{} {}", src, tar);
+            }
+        }
+    }
 }
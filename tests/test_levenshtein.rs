@@ -4,9 +4,14 @@
 // https://docs.rs/approx/latest/approx/
 
 
+mod common;
+
 #[cfg(test)]
 mod tests {
-    use text_distance::Levenshtein;
+    use crate::common::random_strings;
+    use text_distance::{generic_levenshtein, generic_levenshtein_within, Levenshtein};
+
+    const ALPHABET: [char; 5] = ['a', 'b', 'c', 'd', 'ა'];
 
     #[test]
     fn test_distance() {
@@ -43,4 +48,56 @@ mod tests {
         assert_eq!(0.5, Levenshtein {src: "levenshtein".to_string(),  tar: "frankenstein".to_string()}.normalized_similarity());
         assert_eq!(0.5454545454545454, Levenshtein {src: "python is an interpreted language".to_string(),  tar: "rust is a compiled language".to_string()}.normalized_similarity());
     }
+
+    #[test]
+    fn test_borrowed() {
+        let query = "levenshtein";
+        let candidates = ["frankenstein", "levenshtein", ""];
+        let distances: Vec<usize> = candidates.iter().map(|candidate| Levenshtein {src: query,  tar: candidate}.distance()).collect();
+        assert_eq!(vec![6, 0, 11], distances);
+    }
+
+    #[test]
+    fn test_generic() {
+        let reference: Vec<&str> = "python is an interpreted language".split_whitespace().collect();
+        let hypothesis: Vec<&str> = "rust is a compiled language".split_whitespace().collect();
+        assert_eq!(3, generic_levenshtein(&reference, &hypothesis));
+        assert_eq!(2, generic_levenshtein(&[7592, 2088, 999], &[7592, 2154]));
+        assert_eq!(6, generic_levenshtein("levenshtein".as_bytes(), "frankenstein".as_bytes()));
+        assert_eq!(0, generic_levenshtein::<u8>(&[], &[]));
+    }
+
+    #[test]
+    fn test_distance_within() {
+        assert_eq!(Some(0), Levenshtein {src: "",  tar: ""}.distance_within(0));
+        assert_eq!(Some(4), Levenshtein {src: "test",  tar: ""}.distance_within(4));
+        assert_eq!(None, Levenshtein {src: "test",  tar: ""}.distance_within(3));
+        assert_eq!(Some(6), Levenshtein {src: "levenshtein",  tar: "frankenstein"}.distance_within(6));
+        assert_eq!(None, Levenshtein {src: "levenshtein",  tar: "frankenstein"}.distance_within(5));
+        assert_eq!(Some(6), Levenshtein {src: "levenshtein",  tar: "frankenstein"}.distance_within(100));
+
+        let words = ["", "a", "ab", "ba", "abc", "cab", "kitten", "sitting", "saturday", "sunday", "levenshtein", "frankenstein"];
+        for src in words {
+            for tar in words {
+                let distance = generic_levenshtein(src.as_bytes(), tar.as_bytes());
+                for max in 0..8 {
+                    let expected = if distance <= max { Some(distance) } else { None };
+                    assert_eq!(expected, generic_levenshtein_within(src.as_bytes(), tar.as_bytes(), max), "{} {} {}", src, tar, max);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_strings() {
+        let strings = random_strings(42, 30, 300, &ALPHABET);
+        for src in &strings {
+            for tar in &strings {
+                let levenshtein = Levenshtein {src, tar};
+                // the edit script is backtracked through the full matrix
+                let expected = levenshtein.edit_script().iter().filter(|op| op.is_edit()).count();
+                assert_eq!(expected, levenshtein.distance());
+            }
+        }
+    }
 }
@@ -0,0 +1,138 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{Error, Jaccard, LshIndex, SimilarPair, Whitespace};
+
+    const LISTINGS: [&str; 6] = [
+        "Apple iPhone 13 Pro Max 256GB Graphite Unlocked",
+        "Samsung Galaxy S21 Ultra 5G 128GB Phantom Black",
+        "Apple iPhone 13 Pro Max 256GB Graphite - Unlocked",
+        "Sony WH-1000XM4 Wireless Noise Cancelling Headphones",
+        "Samsung Galaxy S21 Ultra 5G 128GB Phantom Black Refurbished",
+        "Sony WH-1000XM4 Wireless Noise Cancelling Headphones Black",
+    ];
+
+    // linear congruential generator, so that the documents are the same on every run
+    fn random_words(count: usize, state: &mut u64) -> Vec<String> {
+        (0..count)
+            .map(|_| {
+                *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                format!("w{}", (*state >> 33) % 10000)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_similar_pairs() {
+        let mut index = LshIndex::new(32, 4, 7, Whitespace).unwrap();
+        for listing in LISTINGS {
+            index.insert(listing);
+        }
+
+        let pairs = index.similar_pairs(0.6);
+        assert_eq!(vec![(0, 2), (1, 4), (3, 5)], pairs.iter().map(|pair| (pair.first, pair.second)).collect::<Vec<_>>());
+        for pair in pairs {
+            let exact = Jaccard {src: LISTINGS[pair.first],  tar: LISTINGS[pair.second], qval: Whitespace}.similarity();
+            assert_eq!(exact, pair.similarity);
+        }
+        assert_eq!(Vec::<SimilarPair>::new(), index.similar_pairs(1.0));
+    }
+
+    #[test]
+    fn test_candidates_are_verified() {
+        let mut index = LshIndex::new(64, 1, 0, 1).unwrap();
+        for listing in LISTINGS {
+            index.insert(listing);
+        }
+
+        // single row bands make almost every pair a candidate, so the threshold is left to the verification
+        let candidates = index.candidate_pairs();
+        let pairs = index.similar_pairs(0.9);
+        assert!(candidates.len() > pairs.len());
+        for pair in pairs {
+            assert!(candidates.contains(&(pair.first, pair.second)));
+            assert!(pair.similarity >= 0.9);
+        }
+    }
+
+    #[test]
+    fn test_incremental() {
+        let mut index = LshIndex::new(20, 5, 42, 3).unwrap();
+        assert!(index.is_empty());
+        assert_eq!(0, index.insert(LISTINGS[0]));
+        assert_eq!(1, index.insert(LISTINGS[1]));
+        assert_eq!(Vec::<SimilarPair>::new(), index.similar_pairs(0.8));
+
+        let matches = index.query(LISTINGS[2], 0.8).unwrap();
+        assert_eq!(vec![0], matches.iter().map(|&(id, _)| id).collect::<Vec<_>>());
+        assert_eq!(2, index.len());
+
+        assert_eq!(2, index.insert(LISTINGS[2]));
+        assert_eq!(vec![SimilarPair {first: 0, second: 2, similarity: matches[0].1}], index.similar_pairs(0.8));
+        assert_eq!(Ok(vec![(0, 1.0), (2, matches[0].1)]), index.query(LISTINGS[0], 0.8));
+    }
+
+    #[test]
+    fn test_recall() {
+        let mut state = 1;
+        let mut index = LshIndex::new(20, 5, 3, Whitespace).unwrap();
+        for _ in 0..100 {
+            let words = random_words(50, &mut state);
+            // a near duplicate with 5 of 50 words replaced has a Jaccard index of about 0.8
+            let mut duplicate = words.clone();
+            duplicate.splice(..5, random_words(5, &mut state));
+            index.insert(&words.join(" "));
+            index.insert(&duplicate.join(" "));
+        }
+
+        let pairs = index.similar_pairs(0.5);
+        assert_eq!(100, pairs.len());
+        for (i, pair) in pairs.iter().enumerate() {
+            assert_eq!((2 * i, 2 * i + 1), (pair.first, pair.second));
+        }
+        // unrelated documents share almost no words, so they are rarely even candidates
+        assert!(index.candidate_pairs().len() < 110);
+    }
+
+    #[test]
+    fn test_threshold() {
+        assert_eq!(0.5492802716530588, LshIndex::new(20, 5, 0, 1).unwrap().threshold());
+        assert_eq!(0.5, LshIndex::new(2, 1, 0, 1).unwrap().threshold());
+        assert_eq!(1.0, LshIndex::new(1, 8, 0, 1).unwrap().threshold());
+    }
+
+    #[test]
+    fn test_empty_documents() {
+        let mut index = LshIndex::new(4, 4, 0, 0).unwrap();
+        index.insert("");
+        index.insert("red cotton t-shirt");
+        index.insert("   ");
+        assert_eq!(vec![SimilarPair {first: 0, second: 2, similarity: 1.0}], index.similar_pairs(0.5));
+        assert_eq!(Ok(vec![(0, 1.0), (2, 1.0)]), index.query("", 0.5));
+    }
+
+    #[test]
+    fn test_try_insert() {
+        let mut index = LshIndex::new(4, 4, 0, 3).unwrap();
+        assert_eq!(Err(Error::InputTooShort {len: 2, min_len: 3}), index.try_insert("ab"));
+        assert_eq!(Err(Error::InputTooShort {len: 2, min_len: 3}), index.query("ab", 0.5));
+        assert!(index.is_empty());
+        assert_eq!(Ok(0), index.try_insert("abc"));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(matches!(LshIndex::new(0, 4, 0, 1), Err(Error::InvalidParameter {name: "bands", ..})));
+        assert!(matches!(LshIndex::new(4, 0, 0, 1), Err(Error::InvalidParameter {name: "rows", ..})));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_panics() {
+        LshIndex::new(4, 4, 0, 3).unwrap().insert("ab");
+    }
+}
@@ -0,0 +1,56 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{DamerauLevenshtein, DynStringMetric, Error, Hamming, Jaccard, JaroWinkler, Levenshtein, StringMetric};
+
+    fn generic_distance<M: StringMetric>(metric: &M) -> M::Output {
+        metric.distance()
+    }
+
+    fn metrics(src: &str, tar: &str) -> Vec<Box<DynStringMetric<'static>>> {
+        vec![
+            Levenshtein {src: src.to_string(),  tar: tar.to_string()}.boxed(),
+            DamerauLevenshtein {src: src.to_string(),  tar: tar.to_string(), restricted: true}.boxed(),
+            Hamming {src: src.to_string(),  tar: tar.to_string()}.boxed(),
+            Jaccard {src: src.to_string(),  tar: tar.to_string(), qval: 1}.boxed(),
+            JaroWinkler {src: src.to_string(),  tar: tar.to_string(), winklerize: true}.boxed(),
+        ]
+    }
+
+    #[test]
+    fn test_generic_distance() {
+        assert_eq!(3, generic_distance(&Levenshtein {src: "karolin".to_string(),  tar: "kathrin".to_string()}));
+        assert_eq!(2, generic_distance(&DamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false}));
+        assert_eq!(3, generic_distance(&Hamming {src: "karolin".to_string(),  tar: "kathrin".to_string()}));
+        assert_eq!(0.4444444444444444, generic_distance(&Jaccard {src: "karolin".to_string(),  tar: "kathrin".to_string(), qval: 1}));
+        assert_eq!(0.15238095238095228, generic_distance(&JaroWinkler {src: "karolin".to_string(),  tar: "kathrin".to_string(), winklerize: true}));
+    }
+
+    #[test]
+    fn test_dyn_distance() {
+        let distances: Vec<f64> = metrics("karolin", "kathrin").iter().map(|metric| metric.distance()).collect();
+        assert_eq!(vec![3.0, 3.0, 3.0, 0.4444444444444444, 0.15238095238095228], distances);
+    }
+
+    #[test]
+    fn test_dyn_similarity() {
+        let similarities: Vec<f64> = metrics("karolin", "kathrin").iter().map(|metric| metric.similarity()).collect();
+        assert_eq!(vec![4.0, 4.0, 4.0, 0.5555555555555556, 0.8476190476190477], similarities);
+    }
+
+    #[test]
+    fn test_dyn_normalized_similarity() {
+        let similarities: Vec<f64> = metrics("karolin", "kathrin").iter().map(|metric| metric.normalized_similarity()).collect();
+        assert_eq!(vec![0.5714285714285714, 0.5714285714285714, 0.5714285714285714, 0.5555555555555556, 0.8476190476190477], similarities);
+    }
+
+    #[test]
+    fn test_dyn_try_distance() {
+        let distances: Vec<Result<f64, Error>> = metrics("test", "textt").iter().map(|metric| metric.try_distance()).collect();
+        assert_eq!(vec![Ok(2.0), Ok(2.0), Err(Error::LengthMismatch {src_len: 4, tar_len: 5}), Ok(0.5), Ok(0.17333333333333334)], distances);
+    }
+}
@@ -0,0 +1,136 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{Error, Jaccard, MinHash, MinHashSignature, Whitespace};
+
+    // linear congruential generator, so that the documents are the same on every run
+    fn random_documents(count: usize, seed: u64) -> Vec<(String, String)> {
+        let mut state = seed;
+        let mut next = |bound: u64| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) % bound
+        };
+
+        (0..count)
+            .map(|_| {
+                let len = 20 + next(200) as usize;
+                let src: Vec<String> = (0..len).map(|_| format!("w{}", next(500))).collect();
+                // replace a random share of the words, so that the pairs cover the whole range of similarities
+                let replaced = next(101);
+                let tar: Vec<String> = src
+                    .iter()
+                    .map(|word| if next(100) < replaced { format!("w{}", next(500)) } else { word.clone() })
+                    .collect();
+                (src.join(" "), tar.join(" "))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let min_hash = MinHash {num_hashes: 4, seed: 42, qval: 2};
+        let signature = min_hash.signature("nelson has a car");
+        assert_eq!(signature, min_hash.signature("nelson has a car"));
+        assert_eq!(signature, MinHash {num_hashes: 4, seed: 42, qval: 2}.signature("nelson has a car"));
+        assert_eq!(42, signature.seed);
+        assert_eq!(4, signature.values.len());
+        // the hash functions must not change between releases, or stored signatures become useless
+        assert_eq!(
+            vec![1822107757738446878, 1162186449224498380, 688734948681972794, 33244807946663894],
+            signature.values
+        );
+        assert_ne!(signature, MinHash {num_hashes: 4, seed: 43, qval: 2}.signature("nelson has a car"));
+    }
+
+    #[test]
+    fn test_set_semantics() {
+        let min_hash = MinHash {num_hashes: 32, seed: 1, qval: 0};
+        assert_eq!(min_hash.signature("a b c"), min_hash.signature("c b a a"));
+        assert_eq!(1.0, min_hash.signature("").similarity(&min_hash.signature("   ")));
+        assert_eq!(0.0, min_hash.signature("a b").similarity(&min_hash.signature("c d")));
+        assert_eq!(1.0, min_hash.signature("a b").distance(&min_hash.signature("c d")));
+    }
+
+    #[test]
+    fn test_error_bounds() {
+        let num_hashes = 256;
+        let min_hash = MinHash {num_hashes, seed: 2023, qval: Whitespace};
+        let documents = random_documents(200, 7);
+
+        let mut total_error = 0.0;
+        let mut total_abs_error = 0.0;
+        for (src, tar) in &documents {
+            let exact = Jaccard {src,  tar, qval: Whitespace}.similarity();
+            let estimate = min_hash.signature(src).similarity(&min_hash.signature(tar));
+            let standard_error = (exact * (1.0 - exact) / num_hashes as f64).sqrt();
+
+            assert!(
+                (estimate - exact).abs() <= 4.0 * standard_error + 1e-12,
+                "estimate {} of {} is more than 4 standard errors off", estimate, exact
+            );
+            total_error += estimate - exact;
+            total_abs_error += (estimate - exact).abs();
+        }
+
+        // unbiased, and on average well within the largest standard error of 0.5 / sqrt(256)
+        let count = documents.len() as f64;
+        assert!((total_error / count).abs() < 0.01);
+        assert!(total_abs_error / count < 0.03);
+    }
+
+    #[test]
+    fn test_more_hashes_are_more_accurate() {
+        let documents = random_documents(100, 11);
+        let mean_error = |num_hashes| {
+            let min_hash = MinHash {num_hashes, seed: 5, qval: 0};
+            documents
+                .iter()
+                .map(|(src, tar)| {
+                    let exact = Jaccard {src,  tar, qval: 0}.similarity();
+                    (min_hash.signature(src).similarity(&min_hash.signature(tar)) - exact).abs()
+                })
+                .sum::<f64>()
+                / documents.len() as f64
+        };
+
+        assert!(mean_error(512) < mean_error(16));
+    }
+
+    #[test]
+    fn test_generic() {
+        let min_hash = MinHash {num_hashes: 128, seed: 9, qval: ()};
+        let src: Vec<u32> = (0..300).collect();
+        let tar: Vec<u32> = (100..400).collect();
+        // exact index is 200 / 400
+        let estimate = min_hash.generic_signature(&src).similarity(&min_hash.generic_signature(&tar));
+        assert!((estimate - 0.5).abs() <= 4.0 * (0.25f64 / 128.0).sqrt());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            MinHash {num_hashes: 0, seed: 1, qval: 0}.try_signature("a b"),
+            Err(Error::InvalidParameter {name: "num_hashes", ..})
+        ));
+        assert_eq!(Err(Error::InputTooShort {len: 3, min_len: 4}), MinHash {num_hashes: 8, seed: 1, qval: 4}.try_signature("abc"));
+
+        let short = MinHash {num_hashes: 8, seed: 1, qval: 0}.signature("a b");
+        let long = MinHash {num_hashes: 16, seed: 1, qval: 0}.signature("a b");
+        let reseeded = MinHash {num_hashes: 8, seed: 2, qval: 0}.signature("a b");
+        assert_eq!(Err(Error::LengthMismatch {src_len: 8, tar_len: 16}), short.try_similarity(&long));
+        assert!(matches!(short.try_distance(&reseeded), Err(Error::InvalidParameter {name: "seed", ..})));
+        assert_eq!(Ok(0.0), short.try_distance(&MinHashSignature {seed: 1, values: short.values.clone()}));
+    }
+
+    #[test]
+    #[should_panic(expected = "Signatures must come from MinHash with the same seed and number of hashes")]
+    fn test_incompatible_panic() {
+        let short = MinHash {num_hashes: 8, seed: 1, qval: 0}.signature("a b");
+        let long = MinHash {num_hashes: 16, seed: 1, qval: 0}.signature("a b");
+        short.similarity(&long);
+    }
+}
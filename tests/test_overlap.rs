@@ -0,0 +1,78 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{generic_overlap, CharNgrams, Error, Overlap};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0.19999999999999996, Overlap {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.distance());
+        assert_eq!(0.2666666666666667, Overlap {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.distance());
+        assert_eq!(0.4285714285714286, Overlap {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.distance());
+    }
+
+    #[test]
+    fn test_normalized_distance() {
+        assert_eq!(0.19999999999999996, Overlap {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.normalized_distance());
+        assert_eq!(0.2666666666666667, Overlap {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.normalized_distance());
+        assert_eq!(0.4285714285714286, Overlap {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.normalized_distance());
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(0.8, Overlap {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.similarity());
+        assert_eq!(0.7333333333333333, Overlap {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.similarity());
+        assert_eq!(0.5714285714285714, Overlap {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.similarity());
+    }
+
+    #[test]
+    fn test_normalized_similarity() {
+        assert_eq!(0.8, Overlap {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0}.normalized_similarity());
+        assert_eq!(0.7333333333333333, Overlap {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2}.normalized_similarity());
+        assert_eq!(0.5714285714285714, Overlap {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(0.33333333333333337, Overlap {src: "ქართული",  tar: "ქართველი", qval: 2}.distance());
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        assert_eq!(
+            Overlap {src: "nelson has a car",  tar: "neilsen has a cat", qval: 2}.distance(),
+            Overlap {src: "nelson has a car",  tar: "neilsen has a cat", qval: CharNgrams {n: 2}}.distance()
+        );
+        assert_eq!(Err(Error::InputTooShort {len: 6, min_len: 7}), Overlap {src: "nelson",  tar: "neilsen", qval: 7}.try_distance());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't create tokens from the text")]
+    fn test_overlap_panic() {
+        Overlap {src: "nelson",  tar: "neilsen", qval: 100}.distance();
+    }
+
+    #[test]
+    fn test_subset() {
+        assert_eq!(0.0, Overlap {src: "new york",  tar: "new york city", qval: 0}.distance());
+        assert_eq!(0.0, Overlap {src: "new york city",  tar: "new york", qval: 0}.distance());
+        assert_eq!(1.0, Overlap {src: "new york",  tar: "los angeles", qval: 0}.distance());
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(0.5, generic_overlap(&[1, 2, 3], &[3, 4]));
+        assert_eq!(0.0, generic_overlap(&[1, 2, 3], &[3, 3, 1]));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(0.0, Overlap {src: "",  tar: "", qval: 0}.distance());
+        assert_eq!(1.0, Overlap {src: "",  tar: "abc", qval: 0}.distance());
+        assert_eq!(0.0, Overlap {src: "abc",  tar: "", qval: 0}.similarity());
+        assert_eq!(1.0, generic_overlap::<u8>(&[1, 2], &[]));
+    }
+}
@@ -0,0 +1,46 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{DamerauLevenshtein, Error, Hamming, Jaccard, JaroWinkler, Levenshtein, Query};
+
+    const CANDIDATES: [&str; 10] = ["", "a", "test", "tset", "textt", "kitten", "sitting", "ალექსანდრე", "ალექსანდრა", "the quick brown fox jumps over the lazy dog, and then it jumps over the lazy dog again"];
+
+    #[test]
+    fn test_same_as_algorithms() {
+        for src in CANDIDATES {
+            let query = Query::new(src);
+            assert_eq!(src, query.as_str());
+            for tar in CANDIDATES {
+                assert_eq!(Levenshtein {src, tar}.distance(), query.levenshtein(tar));
+                assert_eq!(DamerauLevenshtein {src, tar, restricted: true}.distance(), query.damerau_levenshtein(tar, true));
+                assert_eq!(DamerauLevenshtein {src, tar, restricted: false}.distance(), query.damerau_levenshtein(tar, false));
+                assert_eq!(Hamming {src, tar}.try_distance(), query.hamming(tar));
+                assert_eq!(JaroWinkler {src, tar, winklerize: false}.distance(), query.jaro_winkler(tar, false));
+                assert_eq!(JaroWinkler {src, tar, winklerize: true}.distance(), query.jaro_winkler(tar, true));
+                for qval in [0, 1, 2, 3] {
+                    assert_eq!(Jaccard {src, tar, qval}.try_distance(), query.jaccard(tar, qval));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let query = Query::from("test");
+        assert_eq!(Err(Error::LengthMismatch {src_len: 4, tar_len: 5}), query.hamming("textt"));
+        assert_eq!(Err(Error::InputTooShort {len: 2, min_len: 3}), query.jaccard("te", 3));
+        assert_eq!(Ok(0.8), query.jaccard("tent", 2));
+        assert_eq!(Err(Error::InputTooShort {len: 4, min_len: 5}), query.jaccard("tests", 5));
+    }
+
+    #[test]
+    fn test_empty_jaccard() {
+        assert_eq!(Ok(0.0), Query::new("").jaccard("", 0));
+        assert_eq!(Ok(0.0), Query::new(" ").jaccard("", 0));
+        assert_eq!(Ok(1.0), Query::new("").jaccard("test", 1));
+    }
+}
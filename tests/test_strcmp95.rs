@@ -0,0 +1,116 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{Error, SimilarChars, Strcmp95};
+
+    // Name pairs, mostly from Winkler's papers, with the similarities of a port of the original C `strcmp95`,
+    // as (src, tar, with long string adjustment, without).
+    const NAMES: [(&str, &str, f64, f64); 31] = [
+        ("MARTHA", "MARHTA", 0.9708333333333333, 0.9611111111111111),
+        ("DWAYNE", "DUANE", 0.896090909090909, 0.873),
+        ("DIXON", "DICKSONX", 0.853939393939394, 0.8393333333333334),
+        ("SHACKLEFORD", "SHACKELFORD", 0.9886363636363636, 0.9818181818181818),
+        ("DUNNINGHAM", "CUNNIGHAM", 0.9308641975308642, 0.8962962962962964),
+        ("NICHLESON", "NICHULSON", 0.9766666666666667, 0.9688888888888889),
+        ("JONES", "JOHNSON", 0.8738285714285714, 0.8598095238095238),
+        ("MASSEY", "MASSIE", 0.9533333333333333, 0.9533333333333333),
+        ("ABROMS", "ABRAMS", 0.952361111111111, 0.9455555555555555),
+        ("HARDIN", "MARTINEZ", 0.7743055555555556, 0.7222222222222222),
+        ("ITMAN", "SMITH", 0.5066666666666667, 0.5066666666666667),
+        ("JERALDINE", "GERALDINE", 0.9662962962962963, 0.9481481481481482),
+        ("MICHELLE", "MICHAEL", 0.9444444444444444, 0.9375),
+        ("JULIES", "JULIUS", 0.9533333333333333, 0.9533333333333333),
+        ("TANYA", "TONYA", 0.9328, 0.9159999999999999),
+        ("SEAN", "SUSAN", 0.8454999999999999, 0.8454999999999999),
+        ("JON", "JOHN", 0.9333333333333333, 0.9333333333333333),
+        ("JON", "JAN", 0.86, 0.86),
+        ("WELLER", "WILLAR", 0.8833333333333333, 0.86),
+        ("BROOKHAVEN", "BRROKHAVEN", 0.9644444444444443, 0.9466666666666665),
+        ("BROOK HALLOW", "BROOK HLLW", 0.9770833333333333, 0.9666666666666667),
+        ("DECATUR", "DECATIR", 0.965, 0.96),
+        ("FITZRUREITER", "FITZENREITER", 0.9446296296296296, 0.9233333333333333),
+        ("HIGBEE", "HIGHEE", 0.9319444444444445, 0.9222222222222223),
+        ("HIGBEE", "HIGVEE", 0.952361111111111, 0.9455555555555555),
+        ("LACURA", "LOCURA", 0.9475, 0.9299999999999999),
+        ("IOWA", "IONA", 0.8666666666666667, 0.8666666666666667),
+        ("1ST", "IST", 0.8444444444444444, 0.8444444444444444),
+        ("12345678", "12354687", 0.9166666666666666, 0.9166666666666666),
+        ("O0O0", "0O0O", 0.8333333333333334, 0.8333333333333334),
+        ("ALEXANDRA", "ALEKSANDRA", 0.9576024691358026, 0.9421851851851853),
+    ];
+
+    #[test]
+    fn test_reference() {
+        let similar = SimilarChars::default();
+        for (src, tar, long, short) in NAMES {
+            assert_eq!(long, Strcmp95 {src, tar, similar: &similar, long_strings: true}.similarity(), "{} {}", src, tar);
+            assert_eq!(short, Strcmp95 {src, tar, similar: &similar, long_strings: false}.similarity(), "{} {}", src, tar);
+        }
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0.029166666666666674, Strcmp95 {src: "MARTHA", tar: "MARHTA", similar: SimilarChars::default(), long_strings: true}.distance());
+        assert_eq!(0.10390909090909095, Strcmp95 {src: "DWAYNE".to_string(), tar: "DUANE".to_string(), similar: SimilarChars::default(), long_strings: true}.normalized_distance());
+        assert_eq!(1.0, Strcmp95 {src: "ABC", tar: "XYZ", similar: SimilarChars::default(), long_strings: true}.distance());
+        assert_eq!(0.0, Strcmp95 {src: "", tar: "", similar: SimilarChars::default(), long_strings: true}.distance());
+        assert_eq!(1.0, Strcmp95 {src: "ABC", tar: "", similar: SimilarChars::default(), long_strings: true}.distance());
+    }
+
+    #[test]
+    fn test_similar_chars() {
+        // without a table of similar characters, strcmp95 without the long string adjustment is Jaro-Winkler
+        let empty = SimilarChars::empty();
+        assert_eq!(0.8400000000000001, Strcmp95 {src: "DWAYNE", tar: "DUANE", similar: &empty, long_strings: false}.similarity());
+
+        let mut ocr = SimilarChars::empty();
+        ocr.insert('0', 'O', 1.0);
+        assert_eq!(1.0, ocr.get('O', '0'));
+        assert_eq!(0.0, ocr.get('O', 'Q'));
+        assert_eq!(1.0, Strcmp95 {src: "B0B", tar: "BOB", similar: &ocr, long_strings: false}.similarity());
+        // the table is case-sensitive, as in the original which converts strings to upper case first
+        assert_eq!(
+            Strcmp95 {src: "DWAYNE", tar: "DUANE", similar: SimilarChars::default(), long_strings: false}.similarity(),
+            Strcmp95 {src: "dwayne".to_uppercase(), tar: "Duane".to_uppercase(), similar: SimilarChars::default(), long_strings: false}.similarity()
+        );
+        assert_eq!(0.8400000000000001, Strcmp95 {src: "dwayne", tar: "duane", similar: SimilarChars::default(), long_strings: false}.similarity());
+    }
+
+    #[test]
+    fn test_parse() {
+        let table = SimilarChars::parse("
+            # OCR confusions
+            O 0 0.5
+
+            l 1 0.25
+        ").unwrap();
+        assert_eq!(0.5, table.get('0', 'O'));
+        assert_eq!(0.25, table.get('l', '1'));
+        assert_eq!(0.0, table.get('A', 'E'));
+
+        assert_eq!(Err(Error::Parse {line: 1, reason: "expected `a b credit`".to_string()}), SimilarChars::parse("O 0"));
+        assert_eq!(Err(Error::Parse {line: 2, reason: "expected a single character".to_string()}), SimilarChars::parse("O 0 0.5\nOO 0 0.5"));
+        assert_eq!(Err(Error::Parse {line: 1, reason: "expected a number".to_string()}), SimilarChars::parse("O 0 x"));
+        assert_eq!(Err(Error::Parse {line: 1, reason: "credit must be between 0 and 1".to_string()}), SimilarChars::parse("O 0 1.5"));
+    }
+
+    #[test]
+    fn test_invalid_table() {
+        let mut table = SimilarChars::default();
+        table.insert('O', '0', -0.5);
+        assert!(matches!(Strcmp95 {src: "B0B", tar: "BOB", similar: &table, long_strings: true}.try_distance(), Err(Error::InvalidParameter {name: "similar", ..})));
+        assert!(matches!(Strcmp95 {src: "B0B", tar: "BOB", similar: &table, long_strings: true}.try_similarity(), Err(Error::InvalidParameter {name: "similar", ..})));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_table_panics() {
+        let mut table = SimilarChars::empty();
+        table.insert('O', '0', f64::NAN);
+        Strcmp95 {src: "B0B", tar: "BOB", similar: table, long_strings: true}.distance();
+    }
+}
@@ -0,0 +1,110 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{CharNgrams, Chars, Error, Jaccard, PaddedCharNgrams, Tokenizer, TokenizerFn, Whitespace, WordNgrams, Words};
+
+    fn tokens(tokens: &[&str]) -> Result<Vec<String>, Error> {
+        Ok(tokens.iter().map(|token| token.to_string()).collect())
+    }
+
+    #[test]
+    fn test_qval() {
+        assert_eq!(Whitespace.tokenize(" data is  oil "), 0.tokenize(" data is  oil "));
+        assert_eq!(Chars.tokenize("ქართ"), 1.tokenize("ქართ"));
+        assert_eq!(CharNgrams {n: 3}.tokenize("ქართ"), 3.tokenize("ქართ"));
+        assert_eq!(Err(Error::InputTooShort {len: 4, min_len: 5}), 5.tokenize("ქართ"));
+    }
+
+    #[test]
+    fn test_builtin() {
+        assert_eq!(tokens(&["data", "is", "oil"]), Whitespace.tokenize(" data is  oil "));
+        assert_eq!(tokens(&[]), Whitespace.tokenize("   "));
+        assert_eq!(tokens(&["ქ", "ა", "რ", "თ"]), Chars.tokenize("ქართ"));
+        assert_eq!(tokens(&["ქარ", "ართ"]), CharNgrams {n: 3}.tokenize("ქართ"));
+        assert_eq!(tokens(&["data is", "is the", "the new", "new oil"]), WordNgrams {n: 2}.tokenize("data is the new oil"));
+        assert_eq!(tokens(&["data is the new oil"]), WordNgrams {n: 5}.tokenize("data is\tthe\nnew oil"));
+        assert_eq!(Err(Error::InputTooShort {len: 2, min_len: 3}), WordNgrams {n: 3}.tokenize("new york"));
+        assert!(matches!(WordNgrams {n: 0}.tokenize("new york"), Err(Error::InvalidParameter {name: "n", ..})));
+    }
+
+    #[test]
+    fn test_padded() {
+        assert_eq!(tokens(&["#a", "ab", "b#"]), PaddedCharNgrams::new(2).tokenize("ab"));
+        assert_eq!(tokens(&["^^ქ", "^ქა", "ქა$", "ა$$"]), PaddedCharNgrams {n: 3, start: '^', end: '$'}.tokenize("ქა"));
+        assert_eq!(tokens(&["^^a", "^a$", "a$$"]), PaddedCharNgrams {n: 3, start: '^', end: '$'}.tokenize("a"));
+        assert_eq!(tokens(&["^$"]), PaddedCharNgrams {n: 2, start: '^', end: '$'}.tokenize(""));
+        assert_eq!(tokens(&["a", "b"]), PaddedCharNgrams::new(1).tokenize("ab"));
+        assert_eq!(tokens(&[]), PaddedCharNgrams::new(1).tokenize(""));
+        assert!(matches!(PaddedCharNgrams::new(0).tokenize("ab"), Err(Error::InvalidParameter {name: "n", ..})));
+    }
+
+    #[test]
+    fn test_padded_jaccard() {
+        // without padding the strings are too short for trigrams
+        assert_eq!(Err(Error::InputTooShort {len: 2, min_len: 3}), Jaccard {src: "ab",  tar: "abc", qval: 3}.try_distance());
+        assert_eq!(Ok(0.7142857142857143), Jaccard {src: "ab",  tar: "abc", qval: PaddedCharNgrams::new(3)}.try_distance());
+        // a shared prefix and suffix count more with padding
+        assert_eq!(0.2222222222222222, Jaccard {src: "nelson",  tar: "neilsen", qval: 2}.similarity());
+        assert_eq!(0.36363636363636365, Jaccard {src: "nelson",  tar: "neilsen", qval: PaddedCharNgrams {n: 2, start: '^', end: '$'}}.similarity());
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(tokens(&["don", "t", "stop", "2", "3"]), Words::default().tokenize("don't stop... 2.3"));
+        assert_eq!(tokens(&["don't", "stop", "2.3"]), Words {keep: "'."}.tokenize("don't stop 2.3"));
+        assert_eq!(tokens(&["#rustlang", "@ferris", "hi"]), Words {keep: "#@"}.tokenize("#rustlang, @ferris: hi!"));
+        assert_eq!(tokens(&["snake_case", "camelCase"]), Words {keep: "_"}.tokenize("snake_case(camelCase);"));
+        assert_eq!(tokens(&["გამარჯობა", "მსოფლიო"]), Words::default().tokenize("გამარჯობა, მსოფლიო!"));
+    }
+
+    #[test]
+    fn test_function() {
+        let camel_case = TokenizerFn {
+            function: |text: &str| {
+                let mut tokens: Vec<String> = Vec::new();
+                for c in text.chars() {
+                    match tokens.last_mut() {
+                        Some(token) if !c.is_uppercase() => token.push(c),
+                        _ => tokens.push(c.to_string()),
+                    }
+                }
+                tokens
+            },
+        };
+        assert_eq!(tokens(&["parse", "Http", "Request"]), camel_case.tokenize("parseHttpRequest"));
+        assert_eq!(tokens(&[]), camel_case.tokenize(""));
+    }
+
+    #[test]
+    fn test_jaccard() {
+        assert_eq!(0.8, Jaccard {src: "#rustlang is great",  tar: "rustlang is fun", qval: Whitespace}.distance());
+        assert_eq!(0.5, Jaccard {src: "#rustlang is great",  tar: "rustlang is fun", qval: Words::default()}.distance());
+        assert_eq!(0.6666666666666666, Jaccard {src: "data is the new oil",  tar: "data is a new oil", qval: Whitespace}.similarity());
+        assert_eq!(0.33333333333333326, Jaccard {src: "new york city".to_string(),  tar: "new york state".to_string(), qval: WordNgrams {n: 2}}.similarity());
+        assert_eq!(Err(Error::InputTooShort {len: 2, min_len: 3}), Jaccard {src: "new york city",  tar: "new york", qval: WordNgrams {n: 3}}.try_distance());
+        assert_eq!(
+            Jaccard {src: "nelson has a car",  tar: "neilsen has a cat", qval: 2}.distance(),
+            Jaccard {src: "nelson has a car",  tar: "neilsen has a cat", qval: CharNgrams {n: 2}}.distance()
+        );
+    }
+
+    #[test]
+    fn test_borrowed_tokenizer() {
+        let tokenizer: &dyn Tokenizer = &Words {keep: "-"};
+        assert_eq!(tokens(&["AB-12", "XL"]), tokenizer.tokenize("AB-12 (XL)"));
+        assert_eq!(0.0, Jaccard {src: "AB-12 (XL)",  tar: "XL, AB-12", qval: tokenizer}.distance());
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_graphemes() {
+        use text_distance::GraphemeNgrams;
+
+        assert_eq!(tokens(&["ne\u{301}", "e\u{301}e"]), GraphemeNgrams {n: 2}.tokenize("ne\u{301}e"));
+        assert_eq!(0.0, Jaccard {src: "ne\u{301}e",  tar: "ne\u{301}e", qval: GraphemeNgrams {n: 3}}.distance());
+    }
+}
@@ -0,0 +1,103 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{generic_tversky, try_generic_tversky, CharNgrams, Dice, Error, Jaccard, Tversky};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0.2857142857142857, Tversky {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0, alpha: 0.2, beta: 0.8}.distance());
+        assert_eq!(0.30379746835443044, Tversky {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2, alpha: 0.2, beta: 0.8}.distance());
+        assert_eq!(0.45945945945945954, Tversky {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3, alpha: 0.2, beta: 0.8}.distance());
+    }
+
+    #[test]
+    fn test_normalized_distance() {
+        assert_eq!(0.2857142857142857, Tversky {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0, alpha: 0.2, beta: 0.8}.normalized_distance());
+        assert_eq!(0.30379746835443044, Tversky {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2, alpha: 0.2, beta: 0.8}.normalized_distance());
+        assert_eq!(0.45945945945945954, Tversky {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3, alpha: 0.2, beta: 0.8}.normalized_distance());
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(0.7142857142857143, Tversky {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0, alpha: 0.2, beta: 0.8}.similarity());
+        assert_eq!(0.6962025316455696, Tversky {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2, alpha: 0.2, beta: 0.8}.similarity());
+        assert_eq!(0.5405405405405405, Tversky {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3, alpha: 0.2, beta: 0.8}.similarity());
+    }
+
+    #[test]
+    fn test_normalized_similarity() {
+        assert_eq!(0.7142857142857143, Tversky {src: "data is the new oil of the digital economy".to_string(),  tar: "data is a new oil".to_string(), qval: 0, alpha: 0.2, beta: 0.8}.normalized_similarity());
+        assert_eq!(0.6962025316455696, Tversky {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 2, alpha: 0.2, beta: 0.8}.normalized_similarity());
+        assert_eq!(0.5405405405405405, Tversky {src: "nelson has a car".to_string(),  tar: "neilsen has a cat".to_string(), qval: 3, alpha: 0.2, beta: 0.8}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(0.41176470588235303, Tversky {src: "ქართული",  tar: "ქართველი", qval: 2, alpha: 0.2, beta: 0.8}.distance());
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        assert_eq!(
+            Tversky {src: "nelson has a car",  tar: "neilsen has a cat", qval: 2, alpha: 0.2, beta: 0.8}.distance(),
+            Tversky {src: "nelson has a car",  tar: "neilsen has a cat", qval: CharNgrams {n: 2}, alpha: 0.2, beta: 0.8}.distance()
+        );
+        assert_eq!(Err(Error::InputTooShort {len: 6, min_len: 7}), Tversky {src: "nelson",  tar: "neilsen", qval: 7, alpha: 0.2, beta: 0.8}.try_distance());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't create tokens from the text, or alpha or beta is negative")]
+    fn test_tversky_panic() {
+        Tversky {src: "nelson",  tar: "neilsen", qval: 100, alpha: 0.2, beta: 0.8}.distance();
+    }
+
+    #[test]
+    fn test_special_cases() {
+        let (src, tar) = ("nelson has a car", "neilsen has a cat");
+        assert_eq!(Jaccard {src,  tar, qval: 2}.distance(), Tversky {src,  tar, qval: 2, alpha: 1.0, beta: 1.0}.distance());
+        assert_eq!(0.29032258064516125, Tversky {src,  tar, qval: 2, alpha: 0.5, beta: 0.5}.distance());
+        assert_eq!(Dice {src,  tar, qval: 2}.distance(), Tversky {src,  tar, qval: 2, alpha: 0.5, beta: 0.5}.distance());
+        // containment of the source in the target
+        assert_eq!(0.0, Tversky {src: "new york",  tar: "new york city", qval: 0, alpha: 1.0, beta: 0.0}.distance());
+        assert_eq!(0.33333333333333337, Tversky {src: "new york city",  tar: "new york", qval: 0, alpha: 1.0, beta: 0.0}.distance());
+    }
+
+    #[test]
+    fn test_try_distance() {
+        assert!(matches!(
+            Tversky {src: "new york",  tar: "new york city", qval: 0, alpha: -0.5, beta: 1.0}.try_distance(),
+            Err(Error::InvalidParameter {name: "alpha", ..})
+        ));
+        assert!(matches!(
+            Tversky {src: "new york",  tar: "new york city", qval: 0, alpha: 0.5, beta: f64::NAN}.try_distance(),
+            Err(Error::InvalidParameter {name: "beta", ..})
+        ));
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(0.75, generic_tversky(&[1, 2, 3], &[3, 4], 1.0, 1.0));
+        assert_eq!(0.6666666666666667, generic_tversky(&[1, 2, 3], &[3, 4], 1.0, 0.0));
+        assert_eq!(Ok(0.5), try_generic_tversky(&[1, 2, 3], &[3, 4], 0.0, 1.0));
+        assert!(try_generic_tversky(&[1, 2, 3], &[3, 4], 0.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(0.0, Tversky {src: "",  tar: "", qval: 0, alpha: 1.0, beta: 1.0}.distance());
+        assert_eq!(1.0, Tversky {src: "",  tar: "abc", qval: 0, alpha: 1.0, beta: 0.0}.distance());
+        assert_eq!(1.0, Tversky {src: "abc",  tar: "", qval: 0, alpha: 0.0, beta: 1.0}.distance());
+        assert_eq!(Ok(0.0), try_generic_tversky::<u8>(&[], &[], 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_zero_weights() {
+        assert_eq!(Ok(1.0), try_generic_tversky(&[1, 2], &[3], 0.0, 0.0));
+        assert_eq!(Ok(0.0), try_generic_tversky(&[1, 2], &[2, 3], 0.0, 0.0));
+        assert_eq!(0.0, Tversky {src: "abc",  tar: "xyz", qval: 1, alpha: 0.0, beta: 0.0}.similarity());
+    }
+}
@@ -0,0 +1,171 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{generic_weighted_damerau_levenshtein, CostMatrix, DamerauLevenshtein, DamerauLevenshteinWeights, Error, LevenshteinWeights, SubstitutionFn, WeightedDamerauLevenshtein};
+
+    // restricted
+    #[test]
+    fn test_distance() {
+        assert_eq!(0, WeightedDamerauLevenshtein {src: "".to_string(),  tar: "".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(4, WeightedDamerauLevenshtein {src: "test".to_string(),  tar: "".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(8, WeightedDamerauLevenshtein {src: "".to_string(),  tar: "test".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(5, WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(1.0, WeightedDamerauLevenshtein {src: "abcd".to_string(),  tar: "bacd".to_string(), restricted: true, weights: LevenshteinWeights::new(1.0, 1.0, 1.5)}.distance());
+    }
+
+    #[test]
+    fn test_normalized_distance() {
+        assert_eq!(0.0, WeightedDamerauLevenshtein {src: "".to_string(),  tar: "".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+        assert_eq!(1.0, WeightedDamerauLevenshtein {src: "test".to_string(),  tar: "".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+        assert_eq!(0.625, WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(0, WeightedDamerauLevenshtein {src: "test".to_string(),  tar: "".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.similarity());
+        assert_eq!(3, WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.similarity());
+    }
+
+    #[test]
+    fn test_normalized_similarity() {
+        assert_eq!(1.0, WeightedDamerauLevenshtein {src: "".to_string(),  tar: "".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.normalized_similarity());
+        assert_eq!(0.375, WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: true, weights: LevenshteinWeights::new(2, 1, 3)}.normalized_similarity());
+    }
+
+    // Unrestricted
+    #[test]
+    fn test_unrestricted_distance() {
+        assert_eq!(0, WeightedDamerauLevenshtein {src: "".to_string(),  tar: "".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(4, WeightedDamerauLevenshtein {src: "test".to_string(),  tar: "".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(4, WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+    }
+
+    #[test]
+    fn test_unrestricted_normalized_distance() {
+        assert_eq!(0.0, WeightedDamerauLevenshtein {src: "".to_string(),  tar: "".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+        assert_eq!(0.5, WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+    }
+
+    #[test]
+    fn test_unrestricted_similarity() {
+        assert_eq!(4, WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)}.similarity());
+    }
+
+    #[test]
+    fn test_unrestricted_normalized_similarity() {
+        assert_eq!(0.5, WeightedDamerauLevenshtein {src: "ca".to_string(),  tar: "abc".to_string(), restricted: false, weights: LevenshteinWeights::new(2, 1, 3)}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_unit_weights() {
+        for (src, tar) in [("", ""), ("test", ""), ("abcdef", "axb123"), ("ca", "abc"), ("a cat", "an act"), ("specter", "spectre")] {
+            for restricted in [true, false] {
+                let weighted = WeightedDamerauLevenshtein {src, tar, restricted, weights: LevenshteinWeights::default()};
+                let damerau_levenshtein = DamerauLevenshtein {src, tar, restricted};
+                assert_eq!(damerau_levenshtein.distance(), weighted.distance());
+                assert_eq!(damerau_levenshtein.normalized_distance(), weighted.normalized_distance());
+                assert_eq!(damerau_levenshtein.similarity(), weighted.similarity());
+            }
+        }
+    }
+
+    #[test]
+    fn test_cost_matrix() {
+        let keyboard = CostMatrix::parse("q w 0.5\ne r 0.5", 1.0).unwrap();
+        assert_eq!(1.0, WeightedDamerauLevenshtein {src: "qwerty",  tar: "wqerty", restricted: true, weights: LevenshteinWeights::new(1.0, 1.0, keyboard.clone())}.distance());
+        assert_eq!(1.0, WeightedDamerauLevenshtein {src: "qwerty",  tar: "qwetry", restricted: false, weights: LevenshteinWeights::new(1.0, 1.0, keyboard.clone())}.distance());
+        assert_eq!(0.5, WeightedDamerauLevenshtein {src: "qwerty",  tar: "qwetry", restricted: false, weights: LevenshteinWeights::new(0.5, 0.5, keyboard)}.distance());
+
+        // cheap substitutions don't make transpositions cheap, so they can be used for the unrestricted distance
+        let ocr = CostMatrix::parse("O 0 0.2", 1.0).unwrap();
+        assert_eq!(Ok(0.4), WeightedDamerauLevenshtein {src: "B00K",  tar: "BOOK", restricted: false, weights: LevenshteinWeights::new(1.0, 1.0, ocr.clone())}.try_distance());
+        assert_eq!(Ok(1.2), WeightedDamerauLevenshtein {src: "0CA",  tar: "OAC", restricted: false, weights: LevenshteinWeights::new(1.0, 1.0, ocr)}.try_distance());
+    }
+
+    #[test]
+    fn test_try_distance() {
+        assert!(matches!(
+            WeightedDamerauLevenshtein {src: "ab",  tar: "ba", restricted: false, weights: LevenshteinWeights::new(1.0, -1.0, 1.0)}.try_distance(),
+            Err(Error::InvalidParameter {name: "deletion", ..})
+        ));
+        assert_eq!(Ok(3), WeightedDamerauLevenshtein {src: "abc",  tar: "bca", restricted: false, weights: LevenshteinWeights::new(10, 10, 1)}.try_distance());
+        assert_eq!(Ok(3), WeightedDamerauLevenshtein {src: "abc",  tar: "bca", restricted: true, weights: LevenshteinWeights::new(10, 10, 1)}.try_distance());
+    }
+
+    #[test]
+    fn test_substitution_fn_above_max() {
+        let too_expensive = SubstitutionFn {function: |_: &char, _: &char| 5usize, max: 1};
+        let weighted_damerau_levenshtein = WeightedDamerauLevenshtein {src: "ab",  tar: "cd", restricted: true, weights: DamerauLevenshteinWeights::new(1, 1, too_expensive, 1)};
+        assert_eq!(Ok(4), weighted_damerau_levenshtein.try_distance());
+        assert_eq!(Ok(0), weighted_damerau_levenshtein.try_similarity());
+        assert_eq!(Ok(1.0), weighted_damerau_levenshtein.try_normalized_distance());
+    }
+
+    #[test]
+    fn test_generic() {
+        let src: Vec<&str> = "new york city".split_whitespace().collect();
+        let tar: Vec<&str> = "york new city".split_whitespace().collect();
+        assert_eq!(1, generic_weighted_damerau_levenshtein(&src, &tar, true, &LevenshteinWeights::new(1, 1, 2)));
+        assert_eq!(4, generic_weighted_damerau_levenshtein(&[2, 0], &[0, 1, 2], false, &LevenshteinWeights::new(2, 1, 3)));
+    }
+
+    #[test]
+    fn test_transposition_cost() {
+        assert_eq!(0.5, WeightedDamerauLevenshtein {src: "recieve",  tar: "receive", restricted: true, weights: DamerauLevenshteinWeights::new(1.0, 1.0, 1.0, 0.5)}.distance());
+        assert_eq!(1.0, WeightedDamerauLevenshtein {src: "recieve",  tar: "receive", restricted: false, weights: DamerauLevenshteinWeights::new(1.0, 1.0, 2.0, 1.0)}.distance());
+        assert_eq!(1.5, WeightedDamerauLevenshtein {src: "ab",  tar: "bac", restricted: true, weights: DamerauLevenshteinWeights::new(1.0, 1.0, 1.0, 0.5)}.distance());
+        assert_eq!(1.5, WeightedDamerauLevenshtein {src: "bac",  tar: "ab", restricted: true, weights: DamerauLevenshteinWeights::new(1.0, 1.0, 1.0, 0.5)}.distance());
+        assert_eq!(2, WeightedDamerauLevenshtein {src: "abcd",  tar: "bacd", restricted: true, weights: DamerauLevenshteinWeights::new(1, 1, 1, 3)}.distance());
+        assert_eq!(3, WeightedDamerauLevenshtein {src: "ca",  tar: "abc", restricted: true, weights: DamerauLevenshteinWeights::new(1, 1, 1, 1)}.distance());
+        assert_eq!(2, WeightedDamerauLevenshtein {src: "ca",  tar: "abc", restricted: false, weights: DamerauLevenshteinWeights::new(1, 1, 1, 1)}.distance());
+        assert_eq!(3, WeightedDamerauLevenshtein {src: "ca",  tar: "abc", restricted: false, weights: DamerauLevenshteinWeights::new(1, 1, 1, 2)}.distance());
+    }
+
+    #[test]
+    fn test_transposition_cost_normalized() {
+        let weighted_damerau_levenshtein = WeightedDamerauLevenshtein {src: "abcd",  tar: "badc", restricted: true, weights: DamerauLevenshteinWeights::new(1.0, 1.0, 2.0, 0.5)};
+        assert_eq!(1.0, weighted_damerau_levenshtein.distance());
+        assert_eq!(0.125, weighted_damerau_levenshtein.normalized_distance());
+        assert_eq!(7.0, weighted_damerau_levenshtein.similarity());
+        assert_eq!(0.875, weighted_damerau_levenshtein.normalized_similarity());
+    }
+
+    #[test]
+    fn test_default_weights() {
+        let pairs = [("ca", "abc"), ("kitten", "sitting"), ("abcdef", "badcfe"), ("ab", "bca"), ("", "abc")];
+        for (src, tar) in pairs {
+            for restricted in [true, false] {
+                assert_eq!(
+                    DamerauLevenshtein {src,  tar, restricted}.distance(),
+                    WeightedDamerauLevenshtein {src,  tar, restricted, weights: DamerauLevenshteinWeights::default()}.distance()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_transposition_cost() {
+        let weights = DamerauLevenshteinWeights::new(1.0, 1.0, 1.0, 0.5);
+        assert_eq!(Ok(0.5), WeightedDamerauLevenshtein {src: "ab",  tar: "ba", restricted: true, weights}.try_distance());
+        assert!(matches!(
+            WeightedDamerauLevenshtein {src: "ab",  tar: "ba", restricted: false, weights: DamerauLevenshteinWeights::new(2.0, 1.0, 1.0, 1.0)}.try_distance(),
+            Err(Error::InvalidParameter {name: "transposition", ..})
+        ));
+        assert!(matches!(
+            WeightedDamerauLevenshtein {src: "ab",  tar: "ba", restricted: true, weights: DamerauLevenshteinWeights::new(1.0, 1.0, 1.0, -0.5)}.try_distance(),
+            Err(Error::InvalidParameter {name: "transposition", ..})
+        ));
+    }
+
+    #[test]
+    fn test_generic_transposition_cost() {
+        let src: Vec<&str> = "new york city".split_whitespace().collect();
+        let tar: Vec<&str> = "york new city".split_whitespace().collect();
+        assert_eq!(1, generic_weighted_damerau_levenshtein(&src, &tar, true, &DamerauLevenshteinWeights::new(1, 1, 2, 1)));
+        assert_eq!(0.75, generic_weighted_damerau_levenshtein(&[3, 1, 4], &[1, 3, 4], false, &DamerauLevenshteinWeights::new(1.0, 0.5, 1.0, 0.75)));
+    }
+}
@@ -0,0 +1,93 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use text_distance::{generic_bag_jaccard, generic_weighted_jaccard, BagJaccard, Error, Idf, TokenWeights, WeightedJaccard, Whitespace};
+
+    fn weights(weights: &[(&str, f64)]) -> HashMap<String, f64> {
+        weights.iter().map(|&(token, weight)| (token.to_string(), weight)).collect()
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0.75, WeightedJaccard {src: "to be or not to be".to_string(),  tar: "to be is to do".to_string(), qval: 0, weights: weights(&[("to", 0.5), ("be", 0.5)])}.distance());
+        assert_eq!(0.4, WeightedJaccard {src: "ქართული ენა ენა".to_string(),  tar: "ქართული ენა".to_string(), qval: 0, weights: weights(&[("ენა", 2.0)])}.distance());
+        assert_eq!(0.0, WeightedJaccard {src: "abc".to_string(),  tar: "abcx".to_string(), qval: 1, weights: weights(&[("x", 0.0)])}.distance());
+    }
+
+    #[test]
+    fn test_normalized_distance() {
+        assert_eq!(0.75, WeightedJaccard {src: "to be or not to be".to_string(),  tar: "to be is to do".to_string(), qval: 0, weights: weights(&[("to", 0.5), ("be", 0.5)])}.normalized_distance());
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(0.25, WeightedJaccard {src: "to be or not to be".to_string(),  tar: "to be is to do".to_string(), qval: 0, weights: weights(&[("to", 0.5), ("be", 0.5)])}.similarity());
+        assert_eq!(0.6, WeightedJaccard {src: "ქართული ენა ენა".to_string(),  tar: "ქართული ენა".to_string(), qval: 0, weights: weights(&[("ენა", 2.0)])}.similarity());
+    }
+
+    #[test]
+    fn test_normalized_similarity() {
+        assert_eq!(0.25, WeightedJaccard {src: "to be or not to be".to_string(),  tar: "to be is to do".to_string(), qval: 0, weights: weights(&[("to", 0.5), ("be", 0.5)])}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_unit_weights() {
+        let pairs = [("the the cat", "the cat"), ("to be or not to be", "to be is to do"), ("a b c", "d e")];
+        for (src, tar) in pairs {
+            assert_eq!(
+                BagJaccard {src,  tar, qval: 0}.distance(),
+                WeightedJaccard {src,  tar, qval: 0, weights: HashMap::new()}.distance()
+            );
+        }
+    }
+
+    #[test]
+    fn test_idf() {
+        let corpus = ["the cat sat", "the dog sat", "the cat ran", ""];
+        let idf = Idf::new(&corpus, &Whitespace).unwrap();
+        assert_eq!(idf.weight("dog"), idf.weight("ran"));
+        assert!(idf.weight("the") < idf.weight("cat"));
+        assert!(idf.weight("cat") < idf.weight("dog"));
+        assert!(idf.weight("dog") < idf.weight("bird"));
+        assert_eq!(Err(Error::InputTooShort {len: 0, min_len: 2}), Idf::new(&corpus, &2));
+
+        // the common "the" matters less than the rare "dog"
+        let with_the = WeightedJaccard {src: "the dog",  tar: "the cat", qval: Whitespace, weights: &idf}.similarity();
+        let without_the = WeightedJaccard {src: "dog",  tar: "cat", qval: Whitespace, weights: &idf}.similarity();
+        assert_eq!(0.0, without_the);
+        assert!(with_the < BagJaccard {src: "the dog",  tar: "the cat", qval: 0}.similarity());
+    }
+
+    #[test]
+    fn test_try_distance() {
+        assert!(matches!(
+            WeightedJaccard {src: "a b",  tar: "b c", qval: 0, weights: weights(&[("c", -1.0)])}.try_distance(),
+            Err(Error::InvalidParameter {name: "weights", ..})
+        ));
+        assert!(matches!(
+            WeightedJaccard {src: "a b",  tar: "b c", qval: 0, weights: weights(&[("a", f64::NAN)])}.try_distance(),
+            Err(Error::InvalidParameter {name: "weights", ..})
+        ));
+        assert_eq!(Err(Error::InputTooShort {len: 3, min_len: 4}), WeightedJaccard {src: "abc",  tar: "abcd", qval: 4, weights: HashMap::new()}.try_distance());
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(0.75, generic_weighted_jaccard(&[1, 2, 2], &[2, 3], |&token| token as f64));
+        assert_eq!(generic_bag_jaccard(&[1, 1, 2], &[1, 2, 2]), generic_weighted_jaccard(&[1, 1, 2], &[1, 2, 2], |_| 1.0));
+    }
+
+    #[test]
+    fn test_zero_weights() {
+        assert_eq!(0.0, WeightedJaccard {src: "",  tar: "", qval: 0, weights: HashMap::new()}.distance());
+        assert_eq!(1.0, WeightedJaccard {src: "",  tar: "a b", qval: 0, weights: HashMap::new()}.distance());
+        assert_eq!(0.0, WeightedJaccard {src: "a b",  tar: "c", qval: 0, weights: weights(&[("a", 0.0), ("b", 0.0), ("c", 0.0)])}.distance());
+        assert_eq!(1.0, WeightedJaccard {src: "a b",  tar: "c", qval: 0, weights: weights(&[("a", 0.0), ("b", 0.0), ("c", 0.0)])}.similarity());
+        assert_eq!(0.0, generic_weighted_jaccard(&[1, 2], &[3], |_| 0.0));
+    }
+}
@@ -0,0 +1,116 @@
+// TODO: Add approximate float comparison
+// https://docs.rs/float-cmp/latest/float_cmp/
+// https://docs.rs/approx/latest/approx/
+
+
+#[cfg(test)]
+mod tests {
+    use text_distance::{generic_weighted_levenshtein, try_generic_weighted_levenshtein, CostMatrix, Error, Levenshtein, LevenshteinWeights, SubstitutionFn, WeightedLevenshtein};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0, WeightedLevenshtein {src: "".to_string(),  tar: "".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(4, WeightedLevenshtein {src: "test".to_string(),  tar: "".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(8, WeightedLevenshtein {src: "".to_string(),  tar: "test".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(12, WeightedLevenshtein {src: "abcdef".to_string(),  tar: "axb123".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.distance());
+        assert_eq!(7.0, WeightedLevenshtein {src: "levenshtein".to_string(),  tar: "frankenstein".to_string(), weights: LevenshteinWeights::new(1.0, 0.5, 1.5)}.distance());
+    }
+
+    #[test]
+    fn test_normalized_distance() {
+        assert_eq!(0.0, WeightedLevenshtein {src: "".to_string(),  tar: "".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+        assert_eq!(1.0, WeightedLevenshtein {src: "test".to_string(),  tar: "".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+        assert_eq!(1.0, WeightedLevenshtein {src: "".to_string(),  tar: "test".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+        assert_eq!(0.6666666666666666, WeightedLevenshtein {src: "abcdef".to_string(),  tar: "axb123".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.normalized_distance());
+        assert_eq!(0.4, WeightedLevenshtein {src: "levenshtein".to_string(),  tar: "frankenstein".to_string(), weights: LevenshteinWeights::new(1.0, 0.5, 1.5)}.normalized_distance());
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(0, WeightedLevenshtein {src: "".to_string(),  tar: "".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.similarity());
+        assert_eq!(0, WeightedLevenshtein {src: "test".to_string(),  tar: "".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.similarity());
+        assert_eq!(0, WeightedLevenshtein {src: "".to_string(),  tar: "test".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.similarity());
+        assert_eq!(6, WeightedLevenshtein {src: "abcdef".to_string(),  tar: "axb123".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.similarity());
+        assert_eq!(10.5, WeightedLevenshtein {src: "levenshtein".to_string(),  tar: "frankenstein".to_string(), weights: LevenshteinWeights::new(1.0, 0.5, 1.5)}.similarity());
+    }
+
+    #[test]
+    fn test_normalized_similarity() {
+        assert_eq!(1.0, WeightedLevenshtein {src: "".to_string(),  tar: "".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.normalized_similarity());
+        assert_eq!(0.0, WeightedLevenshtein {src: "test".to_string(),  tar: "".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.normalized_similarity());
+        assert_eq!(0.0, WeightedLevenshtein {src: "".to_string(),  tar: "test".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.normalized_similarity());
+        assert_eq!(0.33333333333333337, WeightedLevenshtein {src: "abcdef".to_string(),  tar: "axb123".to_string(), weights: LevenshteinWeights::new(2, 1, 3)}.normalized_similarity());
+        assert_eq!(0.6, WeightedLevenshtein {src: "levenshtein".to_string(),  tar: "frankenstein".to_string(), weights: LevenshteinWeights::new(1.0, 0.5, 1.5)}.normalized_similarity());
+    }
+
+    #[test]
+    fn test_unit_weights() {
+        for (src, tar) in [("", ""), ("test", ""), ("abcdef", "axb123"), ("levenshtein", "frankenstein")] {
+            let weighted = WeightedLevenshtein {src, tar, weights: LevenshteinWeights::default()};
+            let levenshtein = Levenshtein {src, tar};
+            assert_eq!(levenshtein.distance(), weighted.distance());
+            assert_eq!(levenshtein.normalized_distance(), weighted.normalized_distance());
+            assert_eq!(levenshtein.similarity(), weighted.similarity());
+        }
+    }
+
+    #[test]
+    fn test_try_distance() {
+        assert_eq!(Ok(1.25), WeightedLevenshtein {src: "rn",  tar: "m", weights: LevenshteinWeights::new(1.0, 0.25, 1.0)}.try_distance());
+        assert!(matches!(
+            WeightedLevenshtein {src: "rn",  tar: "m", weights: LevenshteinWeights::new(1.0, 0.25, -1.0)}.try_distance(),
+            Err(Error::InvalidParameter {name: "substitution", ..})
+        ));
+        assert!(matches!(
+            WeightedLevenshtein {src: "rn",  tar: "m", weights: LevenshteinWeights::new(f64::NAN, 0.25, 1.0)}.try_normalized_similarity(),
+            Err(Error::InvalidParameter {name: "insertion", ..})
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Edit operation weights must not be negative")]
+    fn test_weighted_levenshtein_panic() {
+        WeightedLevenshtein {src: "rn",  tar: "m", weights: LevenshteinWeights::new(1.0, -0.25, 1.0)}.distance();
+    }
+
+    #[test]
+    fn test_cost_matrix() {
+        let ocr = CostMatrix::parse("O 0 0.25\nl 1 0.5", 1.0).unwrap();
+        assert_eq!(0.75, WeightedLevenshtein {src: "1OND0N",  tar: "lONDON", weights: LevenshteinWeights::new(1.0, 1.0, ocr.clone())}.distance());
+        assert_eq!(0.041666666666666664, WeightedLevenshtein {src: "C0FFEE",  tar: "COFFEE", weights: LevenshteinWeights::new(1.0, 1.0, ocr.clone())}.normalized_distance());
+        assert_eq!(1.25, WeightedLevenshtein {src: "C0FFEE",  tar: "TOFFEE", weights: LevenshteinWeights::new(1.0, 1.0, ocr)}.distance());
+    }
+
+    #[test]
+    fn test_invalid_substitution() {
+        let negative_max = SubstitutionFn {function: |_: &char, _: &char| 0.5, max: -1.0};
+        assert!(matches!(
+            WeightedLevenshtein {src: "lONDON",  tar: "1OND0N", weights: LevenshteinWeights::new(1.0, 1.0, negative_max)}.try_distance(),
+            Err(Error::InvalidParameter {name: "substitution", ..})
+        ));
+        assert!(matches!(
+            try_generic_weighted_levenshtein(&['l'], &['1'], &LevenshteinWeights::new(1.0, 1.0, f64::NAN)),
+            Err(Error::InvalidParameter {name: "substitution", ..})
+        ));
+    }
+
+    #[test]
+    fn test_substitution_fn() {
+        let case_insensitive = SubstitutionFn {function: |a: &char, b: &char| if a.eq_ignore_ascii_case(b) { 0 } else { 2 }, max: 2};
+        assert_eq!(0, WeightedLevenshtein {src: "Levenshtein",  tar: "LEVENSHTEIN", weights: LevenshteinWeights::new(1, 1, case_insensitive)}.distance());
+        assert_eq!(2, WeightedLevenshtein {src: "Levenshtein",  tar: "LEVENSTEIN", weights: LevenshteinWeights::new(2, 2, case_insensitive)}.distance());
+
+        let digits = SubstitutionFn {function: |a: &u32, b: &u32| a.abs_diff(*b) as usize, max: 9};
+        assert_eq!(3, generic_weighted_levenshtein(&[1, 2, 3], &[1, 5, 3], &LevenshteinWeights::new(5, 5, digits)));
+    }
+
+    #[test]
+    fn test_substitution_fn_above_max() {
+        let too_expensive = SubstitutionFn {function: |_: &char, _: &char| 5usize, max: 1};
+        let weighted_levenshtein = WeightedLevenshtein {src: "ab",  tar: "cd", weights: LevenshteinWeights::new(1, 1, too_expensive)};
+        assert_eq!(Ok(4), weighted_levenshtein.try_distance());
+        assert_eq!(Ok(0), weighted_levenshtein.try_similarity());
+        assert_eq!(Ok(1.0), weighted_levenshtein.try_normalized_distance());
+        assert_eq!(Ok(0.0), weighted_levenshtein.try_normalized_similarity());
+    }
+}